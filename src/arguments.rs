@@ -1,10 +1,17 @@
 use clap::{
     App,
+    AppSettings,
     Arg,
     ArgMatches,
+    SubCommand,
 };
 
-use crate::input::Item;
+use crate::{
+    input::Item,
+    table::Style,
+    chart::Chart,
+    terminal,
+};
 
 
 /*----------------------------------------------------------------------------*/
@@ -63,6 +70,19 @@ pub fn arguments<'a>() -> ArgMatches<'a>
                                              take multiple space separated \
                                              values");
 
+    let allow_unknown =
+        Arg::with_name("allow_unknown").long("allow-unknown")
+                                       .takes_value(false)
+                                       .help("Allow querying groups, distances \
+                                              and temperatures which are not \
+                                              declared in the 'meta' section");
+
+    let lenient =
+        Arg::with_name("lenient").long("lenient")
+                                 .takes_value(false)
+                                 .help("Ignore unknown keys in the gear list \
+                                        instead of rejecting them");
+
     let sum =
         {
             let values = ["weight", "price"];
@@ -81,7 +101,7 @@ pub fn arguments<'a>() -> ArgMatches<'a>
                               .takes_value(true)
                               .value_name("COLUMN")
                               .multiple(false)
-                              .possible_values(&Item::FIELDS)
+                              .possible_values(&Item::COLUMNS)
                               .default_value(Item::default_field())
                               .help("Sort rows by a specific column");
 
@@ -104,11 +124,160 @@ pub fn arguments<'a>() -> ArgMatches<'a>
                                  .takes_value(true)
                                  .value_name("COLUMN")
                                  .multiple(true)
-                                 .possible_values(&Item::FIELDS)
-                                 .help("Show specified columns only.  Can be \
+                                 .help("Show specified columns only, or the \
+                                        columns of a preset from the meta \
+                                        section given as @PRESET.  Can be \
                                         defined multiple times, or can take \
                                         multiple space separated values");
 
+    let format =
+        {
+            let values = ["table", "json", "csv", "tsv", "markdown", "html",
+                          "lighterpack", "chart", "checklist",
+                          "markdown-checklist"];
+            Arg::with_name("format").short("f")
+                                    .long("format")
+                                    .takes_value(true)
+                                    .value_name("FORMAT")
+                                    .multiple(false)
+                                    .possible_values(&values)
+                                    .default_value(values[0])
+                                    .help("Output format of the found items")
+        };
+
+    let list_separator =
+        Arg::with_name("list_separator").long("list-separator")
+                                        .takes_value(true)
+                                        .value_name("SEPARATOR")
+                                        .multiple(false)
+                                        .default_value("; ")
+                                        .help("Separator of the multiple values \
+                                               of 'distances' and \
+                                               'temperatures' in the CSV and TSV \
+                                               formats");
+
+    let raw =
+        Arg::with_name("raw").long("raw")
+                             .takes_value(false)
+                             .help("Write the raw values of 'weight' and \
+                                    'price' in the CSV and TSV formats instead \
+                                    of the formatted ones");
+
+    let width =
+        Arg::with_name("width").short("w")
+                               .long("width")
+                               .takes_value(true)
+                               .value_name("COLUMNS")
+                               .multiple(false)
+                               .validator(|width|
+                                   match width.parse::<usize>()
+                                   {
+                                       Ok(_) => Ok(()),
+                                       Err(_) => Err(format!("`{}` is not a \
+                                                              valid width",
+                                                             width)),
+                                   })
+                               .help("Maximum width of the table, by default \
+                                      the width of the terminal, if there is \
+                                      one");
+
+    let overflow =
+        {
+            let values = ["wrap", "truncate"];
+            Arg::with_name("overflow").long("overflow")
+                                      .takes_value(true)
+                                      .value_name("OVERFLOW")
+                                      .multiple(false)
+                                      .possible_values(&values)
+                                      .default_value(values[0])
+                                      .help("Wrap the content of the cells not \
+                                             fitting into the table over \
+                                             multiple lines or truncate them")
+        };
+
+    let style =
+        Arg::with_name("style").long("style")
+                               .takes_value(true)
+                               .value_name("STYLE")
+                               .multiple(false)
+                               .possible_values(&Style::NAMES)
+                               .default_value(Style::NAMES[0])
+                               .help("Border style of the table");
+
+    let color =
+        {
+            let values = ["auto", "always", "never"];
+            Arg::with_name("color").long("color")
+                                   .takes_value(true)
+                                   .value_name("WHEN")
+                                   .multiple(false)
+                                   .possible_values(&values)
+                                   .default_value(values[0])
+                                   .help("Color the table, by default only if \
+                                          the output is a terminal and \
+                                          NO_COLOR is not set")
+        };
+
+    let heavy =
+        Arg::with_name("heavy").long("heavy")
+                               .takes_value(true)
+                               .value_name("WEIGHT")
+                               .multiple(false)
+                               .validator(|weight|
+                                   match weight.parse::<i32>()
+                                   {
+                                       Ok(_) => Ok(()),
+                                       Err(_) => Err(format!("`{}` is not a \
+                                                              valid weight",
+                                                             weight)),
+                                   })
+                               .help("Highlight items heavier than the given \
+                                      weight, by default the ones much heavier \
+                                      than the other items of their group");
+
+    let chart_by =
+        Arg::with_name("chart_by").long("chart-by")
+                                  .takes_value(true)
+                                  .value_name("KEY")
+                                  .multiple(false)
+                                  .possible_values(&Chart::KEYS)
+                                  .default_value(Chart::KEYS[0])
+                                  .help("Draw a bar for each value of the \
+                                         given key in the chart format");
+
+    let chart_of =
+        {
+            let values = ["weight", "price"];
+            Arg::with_name("chart_of").long("chart-of")
+                                      .takes_value(true)
+                                      .value_name("COLUMN")
+                                      .multiple(false)
+                                      .possible_values(&values)
+                                      .default_value(values[0])
+                                      .help("Total shown by the bars in the \
+                                             chart format")
+        };
+
+    let template =
+        Arg::with_name("template").long("template")
+                                  .takes_value(true)
+                                  .value_name("TEMPLATE")
+                                  .multiple(false)
+                                  .conflicts_with("template_file")
+                                  .help("Render the found items with the \
+                                         given template instead of the \
+                                         output format");
+
+    let template_file =
+        Arg::with_name("template_file").long("template-file")
+                                       .takes_value(true)
+                                       .value_name("PATH")
+                                       .multiple(false)
+                                       .help("Render the found items with the \
+                                              template read from the given \
+                                              file instead of the output \
+                                              format");
+
     let path =
         Arg::with_name("path").takes_value(true)
                               .value_name("PATH")
@@ -118,6 +287,166 @@ pub fn arguments<'a>() -> ArgMatches<'a>
                               .help("Specify path to the TOML file contains \
                                      gear items");
 
+    let lint =
+        SubCommand::with_name("lint")
+            .about("Check the gear list for problems, like missing values, \
+                    unusually heavy items or unused meta values")
+            .after_help("The severity of each check could be set in the \
+                         'meta.lint' section to 'error', 'warning' or 'off'");
+
+    let import =
+        SubCommand::with_name("import")
+            .about("Convert a LighterPack CSV export to a gear list and print \
+                    it as TOML")
+            .arg(Arg::with_name("csv").takes_value(true)
+                                      .value_name("CSV")
+                                      .required(true)
+                                      .index(1)
+                                      .help("Path to the LighterPack CSV file"))
+            .after_help("Categories become groups, consumable items go to the \
+                         'consumables' section and weights are converted to \
+                         grams");
+
+    let pack =
+        {
+            let ids =
+                Arg::with_name("ids").takes_value(true)
+                                     .value_name("ID")
+                                     .multiple(true)
+                                     .required(true)
+                                     .help("Id of the item, which is its name, \
+                                            or its kind if it has no name");
+
+            let trip =
+                Arg::with_name("trip").long("trip")
+                                      .takes_value(true)
+                                      .value_name("TRIP")
+                                      .multiple(false)
+                                      .default_value("default")
+                                      .help("Name of the trip to pack for");
+
+            SubCommand::with_name("pack")
+                .about("Keep track of the packed items of a trip")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .arg(trip)
+                .subcommand(SubCommand::with_name("check")
+                                .about("Mark items as packed")
+                                .arg(ids.clone()))
+                .subcommand(SubCommand::with_name("uncheck")
+                                .about("Mark items as not packed")
+                                .arg(ids))
+                .subcommand(SubCommand::with_name("status")
+                                .about("Show the items not packed yet"))
+                .subcommand(SubCommand::with_name("reset")
+                                .about("Mark all items as not packed"))
+                .after_help("The packing state is stored next to the gear \
+                             list, e.g. in 'gear.pack.toml' for 'gear.toml'")
+        };
+
+    let (add, set, remove) =
+        {
+            let id =
+                Arg::with_name("id").takes_value(true)
+                                    .value_name("ID")
+                                    .required(true)
+                                    .index(1)
+                                    .help("Id of the item, which is its name, \
+                                           or its kind if it has no name");
+
+            let assignments =
+                Arg::with_name("assignments").takes_value(true)
+                                             .value_name("FIELD=VALUE")
+                                             .multiple(true)
+                                             .required(true)
+                                             .help("Value of a field, lists \
+                                                    are comma separated and \
+                                                    an empty value removes \
+                                                    the field");
+
+            let edited = "The gear list is changed in place, keeping its \
+                          comments and formatting, and only if the result is \
+                          still valid";
+
+            let add =
+                SubCommand::with_name("add")
+                    .about("Add a new item to the gear list")
+                    .arg(Arg::with_name("consumable").long("consumable")
+                                                     .help("Add the item to \
+                                                            the consumables \
+                                                            instead of the \
+                                                            base items"))
+                    .arg(assignments.clone().index(1));
+
+            let set =
+                SubCommand::with_name("set")
+                    .about("Change the fields of an item in the gear list")
+                    .arg(id.clone())
+                    .arg(assignments.index(2));
+
+            let remove =
+                SubCommand::with_name("remove")
+                    .about("Remove an item from the gear list")
+                    .arg(id);
+
+            (add.after_help(edited), set.after_help(edited), remove.after_help(edited))
+        };
+
+    let rename =
+        SubCommand::with_name("rename")
+            .about("Rename a group, distance or temperature value in the meta \
+                    section and in all the items")
+            .arg(Arg::with_name("attribute").takes_value(true)
+                                            .value_name("ATTRIBUTE")
+                                            .required(true)
+                                            .index(1)
+                                            .possible_values(&["group",
+                                                               "distance",
+                                                               "temperature"])
+                                            .help("Kind of the value"))
+            .arg(Arg::with_name("from").takes_value(true)
+                                       .value_name("FROM")
+                                       .required(true)
+                                       .index(2)
+                                       .help("Current value"))
+            .arg(Arg::with_name("to").takes_value(true)
+                                     .value_name("TO")
+                                     .required(true)
+                                     .index(3)
+                                     .help("New value, if it already exists \
+                                            the current value is merged into \
+                                            it"))
+            .arg(Arg::with_name("dry_run").long("dry-run")
+                                          .help("Show the changes as a diff \
+                                                 without writing the gear list"))
+            .after_help("The gear list is changed in place, keeping its \
+                         comments and formatting, and only if the result is \
+                         still valid");
+
+    let fmt =
+        SubCommand::with_name("fmt")
+            .about("Bring the gear list to its canonical form, keeping its \
+                    comments")
+            .arg(Arg::with_name("sort").long("sort-by")
+                                       .takes_value(true)
+                                       .value_name("FIELD")
+                                       .possible_values(&["kind",
+                                                          "name",
+                                                          "group",
+                                                          "weight",
+                                                          "price",
+                                                          "quantity"])
+                                       .default_value("kind")
+                                       .help("Sort the items of each section \
+                                              by the given field"))
+            .arg(Arg::with_name("check").long("check")
+                                        .help("Show the changes as a diff \
+                                               without writing the gear list, \
+                                               and exit with an error if \
+                                               there are any"))
+            .after_help("The fields of the items are put in a consistent \
+                         order, while the meta values and the distances and \
+                         temperatures of the items are sorted");
+
     let license = "\
 LICENSE:
     Copyright (C) 2020 Peter Varo
@@ -146,11 +475,33 @@ LICENSE:
                           .arg(groups)
                           .arg(distances)
                           .arg(temperatures)
+                          .arg(allow_unknown)
+                          .arg(lenient)
                           .arg(sum)
                           .arg(sort)
                           .arg(order)
                           .arg(columns)
+                          .arg(format)
+                          .arg(list_separator)
+                          .arg(raw)
+                          .arg(width)
+                          .arg(overflow)
+                          .arg(style)
+                          .arg(color)
+                          .arg(heavy)
+                          .arg(chart_by)
+                          .arg(chart_of)
+                          .arg(template)
+                          .arg(template_file)
+                          .subcommand(lint)
+                          .subcommand(import)
+                          .subcommand(pack)
+                          .subcommand(add)
+                          .subcommand(set)
+                          .subcommand(remove)
+                          .subcommand(rename)
+                          .subcommand(fmt)
                           .after_help(license)
-                          .set_term_width(80)
+                          .set_term_width(terminal::width().unwrap_or(80))
                           .get_matches()
 }
@@ -0,0 +1,152 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    input::{
+        Item,
+        Formatters,
+    },
+    totals::Totals,
+};
+
+
+/*----------------------------------------------------------------------------*/
+struct Bar
+{
+    label: String,
+    value: f64,
+    formatted: String,
+    share: f64,
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Chart
+{
+    bars: Vec<Bar>,
+    width: usize,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Chart
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub const KEYS: [&'static str; 4] = ["group", "kind", "distance", "section"];
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const NONE: &'static str = "(none)";
+    const BAR: char = '#';
+    const MIN_BAR_WIDTH: usize = 10;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(items: &[&Item],
+               key: &str,
+               attribute: &str,
+               formatters: Formatters<'_>,
+               width: usize) -> Self
+    {
+        /* Items with multiple distances are counted in each of them, so the
+           shares may add up to more than 100% */
+        let mut buckets = BTreeMap::<String, Vec<&Item>>::new();
+        for &item in items
+        {
+            let labels =
+                match key
+                {
+                    "group" =>
+                        vec![item.group().unwrap_or(Self::NONE).to_string()],
+                    "kind" => vec![item.kind().to_string()],
+                    "distance" =>
+                        item.distances().map(String::from).collect(),
+                    "section" => vec![item.section().to_string()],
+                    _ => unreachable!(),
+                };
+
+            if labels.is_empty()
+            {
+                buckets.entry(Self::NONE.to_string()).or_default().push(item);
+            }
+            for label in labels
+            {
+                buckets.entry(label).or_default().push(item);
+            }
+        }
+
+        let total = Totals::new(items).number(attribute).as_f64();
+        let mut bars =
+            buckets.into_iter()
+                   .map(|(label, items)|
+                       {
+                           let totals = Totals::new(&items);
+                           let value = totals.number(attribute).as_f64();
+                           Bar
+                           {
+                               label,
+                               value,
+                               formatted: totals.formatted(attribute, &formatters),
+                               share: if total == 0.0 { 0.0 }
+                                      else { 100.0*value/total },
+                           }
+                       })
+                   .collect::<Vec<Bar>>();
+
+        /* Heaviest (or most expensive) first */
+        bars.sort_by(|left, right| right.value.total_cmp(&left.value));
+
+        Self { bars, width }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Chart
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        let label_width = self.bars.iter()
+                                   .map(|bar| bar.label.width())
+                                   .max()
+                                   .unwrap_or(0);
+        let value_width = self.bars.iter()
+                                   .map(|bar| bar.formatted.width())
+                                   .max()
+                                   .unwrap_or(0);
+        let max_value = self.bars.iter()
+                                 .map(|bar| bar.value)
+                                 .fold(0.0, f64::max);
+
+        /* Label, bar, value and share ("100.0%") are separated by spaces */
+        let bar_width = self.width.saturating_sub(label_width + value_width + 9)
+                                  .max(Self::MIN_BAR_WIDTH);
+
+        for bar in self.bars.iter()
+        {
+            let length =
+                if max_value <= 0.0 { 0 }
+                else { (bar_width as f64*bar.value/max_value).round() as usize };
+
+            writeln!(f, "{}{:padding$} {}{:rest$} {:>value_width$} {:>5.1}%",
+                     bar.label,
+                     "",
+                     Self::BAR.to_string().repeat(length),
+                     "",
+                     bar.formatted,
+                     bar.share,
+                     padding = label_width - bar.label.width(),
+                     rest = bar_width - length,
+                     value_width = value_width)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+    Write,
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    input::Item,
+    groups::{
+        self,
+        grouped,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Checklist(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Checklist
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const CHECKBOX: &'static str = "[ ]";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* One checkbox for each item, counting its pieces when there are several */
+    pub fn new(items: &[&Item],
+               is_markdown: bool) -> Self
+    {
+        let mut checklist = String::new();
+        for (i, (group, items)) in grouped(items).into_iter().enumerate()
+        {
+            if i > 0
+            {
+                checklist.push('\n');
+            }
+
+            let group = group.unwrap_or(groups::UNGROUPED);
+            if is_markdown
+            {
+                writeln!(checklist, "## {}\n", group).unwrap();
+            }
+            else
+            {
+                writeln!(checklist, "{}\n{}", group, "=".repeat(group.width()))
+                    .unwrap();
+            }
+
+            for item in items
+            {
+                let checkbox =
+                    if is_markdown { format!("- {}", Self::CHECKBOX) }
+                    else { Self::CHECKBOX.to_string() };
+
+                let quantity =
+                    match item.quantity()
+                    {
+                        1 => String::new(),
+                        quantity => format!("{} x ", quantity),
+                    };
+
+                match item.name()
+                {
+                    Some(name) => writeln!(checklist, "{} {}{} ({})",
+                                           checkbox, quantity, item.kind(), name),
+                    None => writeln!(checklist, "{} {}{}",
+                                     checkbox, quantity, item.kind()),
+                }.unwrap();
+            }
+        }
+
+        Self(checklist)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Checklist
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::input::{
+        fixtures,
+        Gear,
+    };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn gear(quantity: u32) -> crate::Result<Gear>
+    {
+        fixtures::gear(&format!("\n[[consumables]]\nkind = \"Gas\"\nname = \"Isopro\"\n\
+                                 group = \"Kitchen\"\nquantity = {}\n",
+                                quantity))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn checklist_has_a_single_checkbox_counting_the_pieces()
+    {
+        let gear = gear(1_000_000).unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+
+        assert_eq!(Checklist::new(&items, false).to_string(),
+                   "Kitchen\n=======\n[ ] 1000000 x Gas (Isopro)\n");
+        assert_eq!(Checklist::new(&items, true).to_string(),
+                   "## Kitchen\n\n- [ ] 1000000 x Gas (Isopro)\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn items_of_no_pieces_are_invalid()
+    {
+        let error = gear(0).unwrap().validate_items().unwrap_err();
+        assert!(error.to_string().starts_with(
+            "Expected a value at least `1` for `quantity` on `Isopro`, but found: `0`"));
+    }
+}
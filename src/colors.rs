@@ -0,0 +1,70 @@
+use crate::{
+    input::{
+        Gear,
+        Item,
+    },
+    column::Emphasis,
+    heavy::HeavyItems,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Colors<'a>
+{
+    heavy_items: HeavyItems<'a>,
+    threshold: Option<i32>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Colors<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(gear: &'a Gear,
+               threshold: Option<i32>) -> Self
+    {
+        Self
+        {
+            heavy_items: HeavyItems::new(gear),
+            threshold,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn header(&self) -> Emphasis
+    {
+        Emphasis::Bold
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn totals(&self) -> Emphasis
+    {
+        Emphasis::Bold
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* Heavy items are either heavier than the threshold, if there is one, or
+       relative to the other items of their group */
+    pub fn item(&self, item: &Item) -> Option<Emphasis>
+    {
+        let is_heavy =
+            match self.threshold
+            {
+                Some(threshold) => item.weight() > threshold,
+                None => self.heavy_items.is_heavy(item),
+            };
+
+        if is_heavy
+        {
+            Some(Emphasis::Red)
+        }
+        else if Item::NUMERIC_FIELDS.iter().any(|field| !item.is_present(field))
+        {
+            Some(Emphasis::Dim)
+        }
+        else
+        {
+            None
+        }
+    }
+}
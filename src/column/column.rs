@@ -1,5 +1,7 @@
 use crate::column::{
     Alignment,
+    Overflow,
+    Emphasis,
     content::Content,
     fitted::FittedColumn,
 };
@@ -10,6 +12,7 @@ pub struct Column
 {
     alignment: Alignment,
     content: Content,
+    emphasis: Option<Emphasis>,
 }
 
 
@@ -20,7 +23,12 @@ impl Column
     pub fn new(alignment: Alignment,
                content: Content) -> Self
     {
-        Self { alignment, content }
+        Self
+        {
+            alignment,
+            content,
+            emphasis: None,
+        }
     }
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
@@ -31,18 +39,42 @@ impl Column
         {
             alignment,
             content: Content::from(Some(content)),
+            emphasis: None,
         }
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn emphasised(self, emphasis: Option<Emphasis>) -> Self
+    {
+        Self { emphasis, ..self }
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn width(&self) -> usize
     {
         self.content.width()
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn word_width(&self) -> usize
+    {
+        self.content.word_width()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn lines(&self, width: usize,
+                        overflow: Overflow) -> Vec<Self>
+    {
+        self.content.lines(width, overflow)
+                    .into_iter()
+                    .map(|content| Self::new(self.alignment, content)
+                                       .emphasised(self.emphasis))
+                    .collect()
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn as_fitted(&self, width: usize) -> FittedColumn<'_>
     {
-        FittedColumn::new(self.alignment, &self.content, width)
+        FittedColumn::new(self.alignment, &self.content, width, self.emphasis)
     }
 }
@@ -1,9 +1,17 @@
-use std::fmt::{
-    self,
-    Display,
-    Formatter,
+use std::{
+    mem,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
 };
 
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::column::Overflow;
+
 
 /*----------------------------------------------------------------------------*/
 pub struct Content(Option<String>);
@@ -12,11 +20,140 @@ pub struct Content(Option<String>);
 /*----------------------------------------------------------------------------*/
 impl Content
 {
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const ELLIPSIS: char = '…';
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn width(&self) -> usize
     {
-        self.0.as_ref().map_or(0, |inner| inner.chars().count())
+        self.0.as_ref().map_or(0, |inner| width_of(inner))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn word_width(&self) -> usize
+    {
+        self.0.iter()
+              .flat_map(|inner| inner.split_whitespace())
+              .map(width_of)
+              .max()
+              .unwrap_or(0)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn lines(&self, width: usize,
+                        overflow: Overflow) -> Vec<Self>
+    {
+        match self.0.as_ref()
+        {
+            Some(inner) if width_of(inner) > width =>
+                match overflow
+                {
+                    Overflow::Wrap =>
+                        wrapped(inner, width).into_iter()
+                                             .map(|line| Self(Some(line)))
+                                             .collect(),
+                    Overflow::Truncate =>
+                        vec![Self(Some(truncated(inner, width)))],
+                },
+            inner => vec![Self(inner.cloned())],
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Display width in terminal columns, where wide (e.g. CJK or emoji)
+   characters take two columns, while combining marks and zero width joiners
+   take none */
+fn width_of(text: &str) -> usize
+{
+    text.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Splits the text at the last grapheme boundary where the first part still
+   fits into the given width */
+fn split_at_width(text: &str, width: usize) -> (&str, &str)
+{
+    let mut used = 0;
+    for (index, grapheme) in text.grapheme_indices(true)
+    {
+        used += grapheme.width();
+        if used > width
+        {
+            return text.split_at(index);
+        }
+    }
+
+    (text, "")
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn truncated(text: &str, width: usize) -> String
+{
+    match width
+    {
+        0 => String::new(),
+        _ =>
+        {
+            let (head, _) = split_at_width(text, width - 1);
+            format!("{}{}", head.trim_end(), Content::ELLIPSIS)
+        },
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn wrapped(text: &str, width: usize) -> Vec<String>
+{
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace()
+    {
+        let line_width = width_of(&line);
+        if line_width > 0
+        {
+            if line_width + 1 + width_of(word) <= width
+            {
+                line.push(' ');
+                line.push_str(word);
+                continue;
+            }
+            lines.push(mem::take(&mut line));
+        }
+
+        /* Words not fitting on a line on their own are broken up, but at
+           least one grapheme is always taken, even if it is wider */
+        let mut rest = word;
+        loop
+        {
+            let (mut chunk, mut remainder) = split_at_width(rest, width);
+            if chunk.is_empty()
+            {
+                let end = rest.graphemes(true).next().map_or(0, str::len);
+                (chunk, remainder) = rest.split_at(end);
+            }
+
+            if remainder.is_empty()
+            {
+                line = chunk.to_string();
+                break;
+            }
+            lines.push(chunk.to_string());
+            rest = remainder;
+        }
     }
+
+    if !line.is_empty() || lines.is_empty()
+    {
+        lines.push(line);
+    }
+
+    lines
 }
 
 
@@ -47,3 +184,61 @@ impl Display for Content
         }
     }
 }
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn wide_characters_take_two_columns()
+    {
+        assert_eq!(width_of("Tent"), 4);
+        assert_eq!(width_of("寝袋"), 4);
+        assert_eq!(width_of("テント 2"), 8);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn combining_marks_take_no_columns()
+    {
+        assert_eq!(width_of("Cafe\u{301}"), 4);
+        assert_eq!(width_of("n\u{303}o\u{308}"), 2);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn joined_emoji_take_the_columns_of_one()
+    {
+        assert_eq!(width_of(FAMILY), 2);
+        assert_eq!(width_of(&format!("{} tent", FAMILY)), 7);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn splits_are_at_grapheme_boundaries()
+    {
+        assert_eq!(split_at_width("寝袋", 3), ("寝", "袋"));
+        assert_eq!(split_at_width("寝袋", 1), ("", "寝袋"));
+        assert_eq!(split_at_width("e\u{301}e\u{301}", 1), ("e\u{301}", "e\u{301}"));
+        assert_eq!(split_at_width(&format!("a{}", FAMILY), 2), ("a", FAMILY));
+        assert_eq!(split_at_width("Tent", 10), ("Tent", ""));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn truncation_and_wrapping_count_columns()
+    {
+        assert_eq!(truncated("寝袋マット", 6), "寝袋…");
+        assert_eq!(truncated("Cafe\u{301} stove", 5), "Cafe\u{301}…");
+        assert_eq!(wrapped("寝袋 マット", 6), vec!["寝袋", "マット"]);
+        assert_eq!(wrapped("寝袋マット", 4), vec!["寝袋", "マッ", "ト"]);
+        assert_eq!(wrapped(FAMILY, 1), vec![FAMILY]);
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy)]
+pub enum Emphasis
+{
+    Bold,
+    Dim,
+    Red,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Emphasis
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub const RESET: &'static str = "\x1b[0m";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn paint<T>(self, text: T) -> String
+        where T: Display
+    {
+        format!("{}{}{}", self, text, Self::RESET)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Emphasis
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        use Emphasis::*;
+        match self
+        {
+            Bold => write!(f, "\x1b[1m"),
+            Dim => write!(f, "\x1b[2m"),
+            Red => write!(f, "\x1b[31m"),
+        }
+    }
+}
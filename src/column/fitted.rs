@@ -5,6 +5,7 @@ use std::fmt::{
 };
 
 use crate::column::{
+    Emphasis,
     content::Content,
     aligned::{
         Alignment,
@@ -25,7 +26,9 @@ enum AlignedFitted<'a>
 
 
 /*----------------------------------------------------------------------------*/
-pub struct FittedColumn<'a>(AlignedFitted<'a>);
+/* The emphasis is applied around the padded content, so it does not affect
+   the width of the column */
+pub struct FittedColumn<'a>(AlignedFitted<'a>, Option<Emphasis>);
 
 
 /*----------------------------------------------------------------------------*/
@@ -34,7 +37,8 @@ impl<'a> FittedColumn<'a>
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn new(alignment: Alignment,
                content: &'a Content,
-               width: usize) -> Self
+               width: usize,
+               emphasis: Option<Emphasis>) -> Self
     {
         use AlignedFitted::*;
         let fitted =
@@ -48,7 +52,7 @@ impl<'a> FittedColumn<'a>
                     Right(RightAlignedFittedColumn::new(content, width)),
             };
 
-        Self(fitted)
+        Self(fitted, emphasis)
     }
 }
 
@@ -60,11 +64,24 @@ impl<'a> Display for FittedColumn<'a>
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
     {
         use AlignedFitted::*;
+
+        if let Some(emphasis) = self.1
+        {
+            write!(f, "{}", emphasis)?;
+        }
+
         match &self.0
         {
-            Left(column) => write!(f, "{}", column),
-            Centre(column) => write!(f, "{}", column),
-            Right(column) => write!(f, "{}", column),
+            Left(column) => write!(f, "{}", column)?,
+            Centre(column) => write!(f, "{}", column)?,
+            Right(column) => write!(f, "{}", column)?,
         }
+
+        if self.1.is_some()
+        {
+            write!(f, "{}", Emphasis::RESET)?;
+        }
+
+        Ok(())
     }
 }
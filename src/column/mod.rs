@@ -1,7 +1,12 @@
+#[allow(clippy::module_inception)]
 mod column;
 mod content;
 mod aligned;
 mod fitted;
+mod overflow;
+mod emphasis;
 
 pub use column::Column;
 pub use aligned::Alignment;
+pub use overflow::Overflow;
+pub use emphasis::Emphasis;
@@ -0,0 +1,6 @@
+#[derive(Clone, Copy)]
+pub enum Overflow
+{
+    Wrap,
+    Truncate,
+}
@@ -0,0 +1,76 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use csv::{
+    Writer,
+    WriterBuilder,
+};
+
+use crate::input::{
+    Item,
+    Value,
+    Formatters,
+    Shares,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Delimited(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Delimited
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(delimiter: u8,
+               columns: &[&str],
+               items: &[&Item],
+               formatters: Formatters<'_>,
+               shares: &Shares,
+               list_separator: &str,
+               is_raw: bool) -> crate::Result<Self>
+    {
+        let mut writer = WriterBuilder::new().delimiter(delimiter)
+                                             .from_writer(Vec::new());
+
+        writer.write_record(columns)?;
+        for item in items
+        {
+            let record = columns.iter().map(
+                |column|
+                    match item.value(column, shares)
+                    {
+                        None => String::new(),
+                        Some(Value::List(values)) => values.join(list_separator),
+                        Some(value) if is_raw => value.to_string(),
+                        Some(_) => item.formatted(column, &formatters, shares).unwrap(),
+                    });
+            writer.write_record(record)?;
+        }
+
+        Ok(Self(into_string(writer)?))
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Delimited
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub fn into_string(writer: Writer<Vec<u8>>) -> crate::Result<String>
+{
+    /* Only strings are written as records, so the output is valid UTF-8 */
+    let output = writer.into_inner().map_err(|error| error.into_error())?;
+    Ok(String::from_utf8(output).unwrap())
+}
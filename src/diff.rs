@@ -0,0 +1,214 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+    Write,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy, PartialEq)]
+enum Change
+{
+    Same,
+    Removed,
+    Added,
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Line based difference of two texts in the unified format */
+pub struct Diff(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Diff
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const CONTEXT: usize = 2;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(path: &str,
+               old: &str,
+               new: &str) -> Self
+    {
+        let changes = changes(&old.lines().collect::<Vec<&str>>(),
+                              &new.lines().collect::<Vec<&str>>());
+
+        let mut diff = String::new();
+        if changes.iter().all(|&(change, _)| change == Change::Same)
+        {
+            return Self(diff);
+        }
+        writeln!(diff, "--- {}\n+++ {}", path, path).unwrap();
+
+        /* Collect the ranges of the changes with their context, merging the
+           ones with overlapping contexts */
+        let mut hunks: Vec<(usize, usize)> = Vec::new();
+        for (index, _) in changes.iter()
+                                 .enumerate()
+                                 .filter(|(_, &(change, _))| change != Change::Same)
+        {
+            let start = index.saturating_sub(Self::CONTEXT);
+            let end = (index + Self::CONTEXT + 1).min(changes.len());
+            match hunks.last_mut()
+            {
+                Some(last) if last.1 >= start => last.1 = end,
+                _ => hunks.push((start, end)),
+            }
+        }
+
+        for (start, end) in hunks
+        {
+            let count = |skipped: Change, range: &[(Change, &str)]|
+                range.iter().filter(|&&(change, _)| change != skipped).count();
+            let old_start = count(Change::Added, &changes[..start]) + 1;
+            let new_start = count(Change::Removed, &changes[..start]) + 1;
+            writeln!(diff, "@@ -{},{} +{},{} @@",
+                     old_start,
+                     count(Change::Added, &changes[start..end]),
+                     new_start,
+                     count(Change::Removed, &changes[start..end])).unwrap();
+
+            for &(change, line) in changes[start..end].iter()
+            {
+                let prefix = match change
+                {
+                    Change::Same => ' ',
+                    Change::Removed => '-',
+                    Change::Added => '+',
+                };
+                writeln!(diff, "{}{}", prefix, line).unwrap();
+            }
+        }
+
+        Self(diff)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn is_empty(&self) -> bool
+    {
+        self.0.is_empty()
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Diff
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Walks the longest common subsequence of the lines, the gear lists are small
+   enough for the quadratic table */
+fn changes<'a>(old: &[&'a str],
+               new: &[&'a str]) -> Vec<(Change, &'a str)>
+{
+    let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev()
+    {
+        for j in (0..new.len()).rev()
+        {
+            lengths[i][j] =
+                if old[i] == new[j] { lengths[i + 1][j + 1] + 1 }
+                else { lengths[i + 1][j].max(lengths[i][j + 1]) };
+        }
+    }
+
+    let mut changes = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len()
+    {
+        if i < old.len() && j < new.len() && old[i] == new[j]
+        {
+            changes.push((Change::Same, old[i]));
+            i += 1;
+            j += 1;
+        }
+        else if j == new.len()
+                || (i < old.len() && lengths[i + 1][j] >= lengths[i][j + 1])
+        {
+            changes.push((Change::Removed, old[i]));
+            i += 1;
+        }
+        else
+        {
+            changes.push((Change::Added, new[j]));
+            j += 1;
+        }
+    }
+
+    changes
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn diff(old: &[&str],
+            new: &[&str]) -> String
+    {
+        Diff::new("gear.toml", &old.join("\n"), &new.join("\n")).to_string()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn change_on_the_first_line()
+    {
+        assert_eq!(diff(&["a", "b", "c", "d"], &["x", "b", "c", "d"]),
+                   "--- gear.toml\n+++ gear.toml\n\
+                    @@ -1,3 +1,3 @@\n-a\n+x\n b\n c\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn change_on_the_last_line()
+    {
+        assert_eq!(diff(&["a", "b", "c", "d"], &["a", "b", "c"]),
+                   "--- gear.toml\n+++ gear.toml\n\
+                    @@ -2,3 +2,2 @@\n b\n c\n-d\n");
+        assert_eq!(diff(&["a", "b", "c", "d"], &["a", "b", "c", "d", "e"]),
+                   "--- gear.toml\n+++ gear.toml\n\
+                    @@ -3,2 +3,3 @@\n c\n d\n+e\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn changes_with_overlapping_contexts_are_merged()
+    {
+        assert_eq!(diff(&["a", "b", "c", "d", "e", "f", "g", "h"],
+                        &["a", "b", "X", "d", "e", "Y", "g", "h"]),
+                   "--- gear.toml\n+++ gear.toml\n\
+                    @@ -1,8 +1,8 @@\n a\n b\n-c\n+X\n d\n e\n-f\n+Y\n g\n h\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn distant_changes_have_their_own_hunks()
+    {
+        assert_eq!(diff(&["a", "b", "c", "d", "e", "f", "g", "h"],
+                        &["X", "b", "c", "d", "e", "f", "g", "Y"]),
+                   "--- gear.toml\n+++ gear.toml\n\
+                    @@ -1,3 +1,3 @@\n-a\n+X\n b\n c\n\
+                    @@ -6,3 +6,3 @@\n f\n g\n-h\n+Y\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn identical_texts_have_no_difference()
+    {
+        let diff = Diff::new("gear.toml", "a\nb\n", "a\nb\n");
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+}
@@ -0,0 +1,985 @@
+use std::{
+    cmp::Ordering,
+    fs,
+};
+
+use toml_edit::{
+    Array,
+    ArrayOfTables,
+    DocumentMut,
+    Item as TomlItem,
+    Key,
+    Table,
+    TableLike,
+    Value,
+};
+
+use crate::{
+    input::{
+        Gear,
+        Item,
+        Source,
+        Section,
+    },
+    diff::Diff,
+    suggest::suggestion,
+};
+
+
+/*----------------------------------------------------------------------------*/
+/* Editable copy of a gear list, which keeps the comments, the order and the
+   formatting of the original file intact */
+pub struct Document
+{
+    file_name: String,
+    original: String,
+    document: DocumentMut,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Document
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The attributes of an item besides its fields, written after them */
+    const OTHER_FIELDS: [&'static str; 3] = ["quantity", "worn", "url"];
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const SECTIONS: [Section; 2] = [Section::Base, Section::Consumables];
+
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn load(file_name: &str) -> crate::Result<Self>
+    {
+        let original = fs::read_to_string(file_name)?;
+        let document = original.parse::<DocumentMut>()
+                               .map_err(|error| format!("{}: {}", file_name, error))?;
+
+        Ok(Self
+        {
+            file_name: file_name.into(),
+            original,
+            document,
+        })
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn save(&self) -> crate::Result<()>
+    {
+        fs::write(&self.file_name, self.document.to_string())?;
+        Ok(())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn validate(&self, is_strict: bool) -> crate::Result<()>
+    {
+        Gear::from_source(Source::new(&self.file_name, self.document.to_string()),
+                          is_strict)?.validate_items()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn diff(&self) -> Diff
+    {
+        Diff::new(&self.file_name, &self.original, &self.document.to_string())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* All the attributes of an item in the order they are written in */
+    pub fn fields() -> impl Iterator<Item = &'static str>
+    {
+        Item::FIELDS.iter().chain(Self::OTHER_FIELDS.iter()).copied()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The value is a comma separated list for `distances` and `temperatures`,
+       and an empty value means the field should be removed */
+    pub fn assignment(assignment: &str) -> crate::Result<(&str, Option<Value>)>
+    {
+        let (field, text) =
+            assignment.split_once('=').ok_or_else(
+                || format!("Expected `FIELD=VALUE`, but found: `{}`", assignment))?;
+        let field = field.trim();
+        let text = text.trim();
+
+        if !Self::fields().any(|known| known == field)
+        {
+            let mut message = format!("Unknown field `{}`", field);
+            if let Some(suggestion) = suggestion(field, Self::fields())
+            {
+                message.push_str(&format!(", did you mean `{}`?", suggestion));
+            }
+            return Err(message.into());
+        }
+
+        if text.is_empty()
+        {
+            return Ok((field, None));
+        }
+
+        let invalid = |expected: &str|
+            format!("Expected {} for `{}`, but found: `{}`", expected, field, text);
+
+        let value = match field
+        {
+            "weight" =>
+                text.parse::<i32>()
+                    .map_err(|_| invalid("an integer"))
+                    .map(i64::from)?
+                    .into(),
+            "quantity" =>
+                text.parse::<u32>()
+                    .map_err(|_| invalid("a positive integer"))
+                    .map(i64::from)?
+                    .into(),
+            "price" =>
+                text.parse::<f64>().map_err(|_| invalid("a number"))?.into(),
+            "worn" =>
+                text.parse::<bool>()
+                    .map_err(|_| invalid("`true` or `false`"))?
+                    .into(),
+            "distances" | "temperatures" =>
+                text.split(',')
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .collect::<Array>()
+                    .into(),
+            _ => text.into(),
+        };
+
+        Ok((field, Some(value)))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn add(&mut self, section: Section,
+                          assignments: &[(&str, Option<Value>)])
+        -> crate::Result<()>
+    {
+        let mut table = Table::new();
+        for field in Self::fields()
+        {
+            let value = assignments.iter()
+                                   .rev()
+                                   .find(|(assigned, _)| *assigned == field)
+                                   .and_then(|(_, value)| value.clone());
+            if let Some(value) = value
+            {
+                table.insert(field, TomlItem::Value(value));
+            }
+        }
+
+        if let Some(id) = Self::id(&table).filter(|id| !self.find(id).is_empty())
+        {
+            return Err(format!("There is already an item with the id `{}`, give \
+                                the new one a different name",
+                               id).into());
+        }
+
+        let key = section.to_string();
+        match self.document.get_mut(&key)
+        {
+            Some(TomlItem::ArrayOfTables(tables)) => tables.push(table),
+            Some(TomlItem::Value(Value::Array(array))) =>
+            {
+                /* Put the new item on its own line if the others are */
+                let mut value = Value::from(table.into_inline_table());
+                let indentation = array.iter()
+                                       .last()
+                                       .and_then(|last| last.decor().prefix())
+                                       .and_then(|prefix| prefix.as_str())
+                                       .and_then(|prefix| prefix.rfind('\n')
+                                                                .map(|i| &prefix[i..]));
+                if let Some(indentation) = indentation
+                {
+                    value.decor_mut().set_prefix(indentation);
+                }
+                array.push_formatted(value);
+            },
+            _ =>
+            {
+                let mut tables = ArrayOfTables::new();
+                tables.push(table);
+                self.document.insert(&key, TomlItem::ArrayOfTables(tables));
+            },
+        }
+
+        Ok(())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn set(&mut self, id: &str,
+                          assignments: Vec<(&str, Option<Value>)>)
+        -> crate::Result<()>
+    {
+        let (section, index) = self.locate(id)?;
+        let is_inline = self.document.get(&section.to_string())
+                                     .is_some_and(TomlItem::is_value);
+        let item = self.item_mut(section, index);
+        for (field, value) in assignments
+        {
+            match value
+            {
+                /* Keep the decoration, e.g. a trailing comment of the field */
+                Some(mut value) => match item.get_mut(field)
+                {
+                    Some(TomlItem::Value(existing)) =>
+                    {
+                        *value.decor_mut() = existing.decor().clone();
+                        *existing = value;
+                    },
+                    _ => insert(item, field, TomlItem::Value(value)),
+                },
+                None =>
+                {
+                    item.remove(field);
+                },
+            }
+        }
+
+        /* Inline tables cannot have comments, so only their spacing is lost */
+        if is_inline
+        {
+            item.fmt();
+        }
+
+        Ok(())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn remove(&mut self, id: &str) -> crate::Result<()>
+    {
+        let (section, index) = self.locate(id)?;
+        match self.document.get_mut(&section.to_string())
+        {
+            Some(TomlItem::ArrayOfTables(tables)) => tables.remove(index),
+            Some(TomlItem::Value(Value::Array(array))) =>
+            {
+                array.remove(index);
+            },
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* Renames the value in the meta section and in all the items, and returns
+       the number of changed values.  If the new value already exists, the old
+       one is merged into it */
+    pub fn rename(&mut self, attribute: &str,
+                             from: &str,
+                             to: &str) -> usize
+    {
+        let (values, field) = match attribute
+        {
+            "group" => ("groups", "group"),
+            "distance" => ("distances", "distances"),
+            "temperature" => ("temperatures", "temperatures"),
+            _ => unreachable!(),
+        };
+
+        let mut renamed = 0;
+        if let Some(meta) = self.document.get_mut("meta")
+                                         .and_then(TomlItem::as_table_like_mut)
+        {
+            if let Some(values) =
+                meta.get_mut(values)
+                    .and_then(TomlItem::as_table_like_mut)
+                    .and_then(|values| values.get_mut("values"))
+                    .and_then(TomlItem::as_value_mut)
+            {
+                renamed += rename(values, from, to);
+            }
+
+            /* Rules could be restricted to a group */
+            if attribute == "group"
+            {
+                let rules = meta.get_mut("rules")
+                                .and_then(TomlItem::as_table_like_mut);
+                for (_, rules) in rules.into_iter().flat_map(|rules| rules.iter_mut())
+                {
+                    for rule in tables_mut(rules)
+                    {
+                        if let Some(group) =
+                            rule.get_mut("when")
+                                .and_then(TomlItem::as_table_like_mut)
+                                .and_then(|when| when.get_mut("group"))
+                                .and_then(TomlItem::as_value_mut)
+                        {
+                            renamed += rename(group, from, to);
+                        }
+                    }
+                }
+            }
+        }
+
+        for section in Self::SECTIONS.iter()
+        {
+            if let Some(items) = self.document.get_mut(&section.to_string())
+            {
+                for item in tables_mut(items)
+                {
+                    if let Some(value) = item.get_mut(field)
+                                             .and_then(TomlItem::as_value_mut)
+                    {
+                        renamed += rename(value, from, to);
+                    }
+                }
+            }
+        }
+
+        renamed
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* In the canonical form the meta values and the list attributes of the
+       items are sorted, the fields of the items are in the order of `fields`,
+       and the items are sorted by the given field within their sections.
+       Comments stay attached to the values they precede */
+    pub fn format(&mut self, sort_by: &str)
+    {
+        if let Some(meta) = self.document.get_mut("meta")
+                                         .and_then(TomlItem::as_table_like_mut)
+        {
+            for values in ["groups", "distances", "temperatures"].iter()
+            {
+                if let Some(Value::Array(values)) =
+                    meta.get_mut(values)
+                        .and_then(TomlItem::as_table_like_mut)
+                        .and_then(|values| values.get_mut("values"))
+                        .and_then(TomlItem::as_value_mut)
+                {
+                    sort(values, |left, right| left.as_str().cmp(&right.as_str()));
+                }
+            }
+        }
+
+        for section in Self::SECTIONS.iter()
+        {
+            let items = match self.document.get_mut(&section.to_string())
+            {
+                Some(items) => items,
+                None => continue,
+            };
+
+            for item in tables_mut(items)
+            {
+                for field in ["distances", "temperatures"].iter()
+                {
+                    if let Some(Value::Array(values)) =
+                        item.get_mut(field).and_then(TomlItem::as_value_mut)
+                    {
+                        sort(values, |left, right| left.as_str().cmp(&right.as_str()));
+                    }
+                }
+            }
+
+            let compare = |left: &dyn TableLike, right: &dyn TableLike|
+                compare_by(left, right, sort_by)
+                    .then_with(|| Self::id(left).cmp(&Self::id(right)));
+
+            match items
+            {
+                TomlItem::ArrayOfTables(tables) =>
+                {
+                    let mut items = tables.iter().cloned().collect::<Vec<Table>>();
+                    for item in items.iter_mut()
+                    {
+                        item.sort_values_by(
+                            |left, _, right, _| rank(left.get()).cmp(&rank(right.get())));
+                    }
+
+                    /* The position of a table is its place in the document, so
+                       the sorted items take the places of the original ones */
+                    let positions = items.iter()
+                                         .map(Table::position)
+                                         .collect::<Vec<Option<usize>>>();
+                    items.sort_by(|left, right| compare(left, right));
+
+                    tables.clear();
+                    for (mut item, position) in items.into_iter().zip(positions)
+                    {
+                        if let Some(position) = position
+                        {
+                            item.set_position(position);
+                        }
+                        tables.push(item);
+                    }
+                },
+                TomlItem::Value(Value::Array(array)) =>
+                {
+                    for item in array.iter_mut().filter_map(Value::as_inline_table_mut)
+                    {
+                        item.sort_values_by(
+                            |left, _, right, _| rank(left.get()).cmp(&rank(right.get())));
+                        item.fmt();
+                    }
+
+                    sort(array, |left, right|
+                        match (left.as_inline_table(), right.as_inline_table())
+                        {
+                            (Some(left), Some(right)) => compare(left, right),
+                            _ => Ordering::Equal,
+                        });
+                },
+                _ => (),
+            }
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn find(&self, id: &str) -> Vec<(Section, usize)>
+    {
+        let mut found = Vec::new();
+        for &section in Self::SECTIONS.iter()
+        {
+            if let Some(items) = self.document.get(&section.to_string())
+            {
+                found.extend(
+                    tables(items).into_iter()
+                                 .enumerate()
+                                 .filter(|(_, item)| Self::id(*item) == Some(id))
+                                 .map(|(index, _)| (section, index)));
+            }
+        }
+
+        found
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The section and the position of the only item with the given id */
+    fn locate(&self, id: &str) -> crate::Result<(Section, usize)>
+    {
+        let found = self.find(id);
+        match found.as_slice()
+        {
+            [] => Err(format!("There is no item with the id `{}`", id).into()),
+            [location] => Ok(*location),
+            _ => Err(format!("There are {} items with the id `{}`, give them \
+                              different names to edit them",
+                             found.len(),
+                             id).into()),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The same as `Item::id`: the name of the item, or its kind if it has none */
+    fn id(table: &dyn TableLike) -> Option<&str>
+    {
+        table.get("name")
+             .or_else(|| table.get("kind"))
+             .and_then(TomlItem::as_str)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn item_mut(&mut self, section: Section,
+                           index: usize) -> &mut dyn TableLike
+    {
+        let items = self.document.get_mut(&section.to_string()).unwrap();
+        tables_mut(items).swap_remove(index)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* The tables of either an array of tables or an array of inline tables */
+fn tables(item: &TomlItem) -> Vec<&dyn TableLike>
+{
+    match item
+    {
+        TomlItem::ArrayOfTables(tables) =>
+            tables.iter().map(|table| table as &dyn TableLike).collect(),
+        TomlItem::Value(Value::Array(array)) =>
+            array.iter()
+                 .filter_map(Value::as_inline_table)
+                 .map(|table| table as &dyn TableLike)
+                 .collect(),
+        _ => Vec::new(),
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn tables_mut(item: &mut TomlItem) -> Vec<&mut dyn TableLike>
+{
+    match item
+    {
+        TomlItem::ArrayOfTables(tables) =>
+            tables.iter_mut().map(|table| table as &mut dyn TableLike).collect(),
+        TomlItem::Value(Value::Array(array)) =>
+            array.iter_mut()
+                 .filter_map(Value::as_inline_table_mut)
+                 .map(|table| table as &mut dyn TableLike)
+                 .collect(),
+        _ => Vec::new(),
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Renames a string value, or the matching values of an array, keeping their
+   decoration */
+fn rename(value: &mut Value,
+          from: &str,
+          to: &str) -> usize
+{
+    match value
+    {
+        Value::String(string) if string.value() == from =>
+        {
+            let decor = string.decor().clone();
+            *value = to.into();
+            *value.decor_mut() = decor;
+            1
+        },
+        Value::Array(array) =>
+        {
+            let position = |name: &str|
+                array.iter().position(|value| value.as_str() == Some(name));
+
+            match (position(from), position(to))
+            {
+                (None, _) => 0,
+                (Some(index), Some(_)) =>
+                {
+                    /* Removing the first value of a single line array would
+                       leave a space after the opening bracket */
+                    array.remove(index);
+                    let is_single_line =
+                        array.iter()
+                             .filter_map(|value| value.decor().prefix())
+                             .filter_map(|prefix| prefix.as_str())
+                             .all(|prefix| !prefix.contains('\n'));
+                    if is_single_line
+                    {
+                        array.fmt();
+                    }
+                    1
+                },
+                (Some(index), None) => rename(array.get_mut(index).unwrap(),
+                                              from,
+                                              to),
+            }
+        },
+        _ => 0,
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* The comments move with the sorted values, unless the array is on a single
+   line, which is then simply reformatted */
+fn sort(array: &mut Array,
+        mut compare: impl FnMut(&Value, &Value) -> Ordering)
+{
+    let prefixes =
+        array.iter()
+             .map(|value| value.decor()
+                               .prefix()
+                               .and_then(|prefix| prefix.as_str())
+                               .unwrap_or_default()
+                               .to_string())
+             .collect::<Vec<String>>();
+
+    if !prefixes.iter().any(|prefix| prefix.contains('\n'))
+    {
+        array.sort_by(compare);
+        array.fmt();
+        return;
+    }
+
+    /* A comment at the end of a line is either the end of the decoration of
+       the value, or the start of the decoration of the next value (or of the
+       end of the array), so it is split from the rest to stay on the line of
+       its value, which is then directly followed by its comma */
+    let split = |text: &str|
+    {
+        let (tail, lead) = text.split_at(text.find('\n').unwrap_or(0));
+        (tail.to_string(), lead.to_string())
+    };
+    let suffix = |value: &Value|
+        split(value.decor()
+                   .suffix()
+                   .and_then(|suffix| suffix.as_str())
+                   .unwrap_or_default()).0;
+    let join = |tail: &str, lead: &str|
+        if tail.contains('#') && !lead.starts_with('\n')
+        {
+            format!("{}\n{}", tail, lead)
+        }
+        else
+        {
+            format!("{}{}", tail, lead)
+        };
+
+    let values = array.iter().cloned().collect::<Vec<Value>>();
+    let (head, lead) = split(&prefixes[0]);
+    let mut leads = vec![lead];
+    let mut tails = Vec::with_capacity(values.len());
+    for (value, prefix) in values.iter().zip(prefixes[1..].iter())
+    {
+        let (tail, lead) = split(prefix);
+        tails.push(suffix(value) + &tail);
+        leads.push(lead);
+    }
+    let (tail, rest) = split(array.trailing().as_str().unwrap_or_default());
+    tails.push(suffix(&values[values.len() - 1]) + &tail);
+
+    let mut order = (0..values.len()).collect::<Vec<usize>>();
+    order.sort_by(|&left, &right| compare(&values[left], &values[right]));
+
+    array.clear();
+    for (position, &index) in order.iter().enumerate()
+    {
+        let before = if position == 0 { &head } else { &tails[order[position - 1]] };
+        let mut value = values[index].clone();
+        value.decor_mut().set_prefix(join(before, &leads[index]));
+        value.decor_mut().set_suffix("");
+        array.push_formatted(value);
+    }
+    array.set_trailing(join(&tails[order[order.len() - 1]], &rest));
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* The place of a field in the canonical order, unknown fields come last */
+fn rank(key: &str) -> usize
+{
+    Document::fields().position(|field| field == key)
+                      .unwrap_or(usize::MAX)
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* A new field goes before the fields ranked after it, where `format` would put
+   it, moving them with their decoration */
+fn insert(item: &mut dyn TableLike, field: &str, value: TomlItem)
+{
+    let following = item.iter()
+                        .filter(|(key, _)| rank(key) > rank(field))
+                        .filter_map(|(key, _)| item.key(key).cloned())
+                        .collect::<Vec<Key>>();
+
+    item.insert(field, value);
+    for key in following
+    {
+        let value = item.remove(key.get()).unwrap();
+        item.entry_format(&key).or_insert(value);
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* The items without the field come last */
+fn compare_by(left: &dyn TableLike,
+              right: &dyn TableLike,
+              field: &str) -> Ordering
+{
+    let value = |item: &dyn TableLike|
+        item.get(field).and_then(TomlItem::as_value).cloned();
+    let number = |value: &Value|
+        value.as_float()
+             .or_else(|| value.as_integer().map(|value| value as f64));
+
+    match (value(left), value(right))
+    {
+        (Some(left), Some(right)) =>
+            match (number(&left), number(&right))
+            {
+                (Some(left), Some(right)) =>
+                    left.partial_cmp(&right).unwrap_or(Ordering::Equal),
+                _ => left.as_str().cmp(&right.as_str()),
+            },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::input::fixtures::META;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const ITEMS: &str = "
+# Shelter
+[[base]]
+kind = \"Tent\"
+name = \"Duplex\"
+weight = 540 # seam sealed
+";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn document(text: &str) -> Document
+    {
+        Document
+        {
+            file_name: "gear.toml".into(),
+            original: text.into(),
+            document: text.parse().unwrap(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn gear(items: &str) -> Document
+    {
+        document(&format!("{}{}", META, items))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn assignments<'a>(assignments: &[&'a str]) -> Vec<(&'a str, Option<Value>)>
+    {
+        assignments.iter()
+                   .map(|assignment| Document::assignment(assignment).unwrap())
+                   .collect()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn assignment_parses_the_value_of_the_field()
+    {
+        let (field, value) = Document::assignment("distances=Short, Long").unwrap();
+        assert_eq!(field, "distances");
+        assert_eq!(value.unwrap().to_string(), r#"["Short", "Long"]"#);
+
+        let (field, value) = Document::assignment("url=").unwrap();
+        assert_eq!(field, "url");
+        assert!(value.is_none());
+
+        assert!(Document::assignment("weight=heavy").is_err());
+        assert!(Document::assignment("wieght=1").unwrap_err()
+                                               .to_string()
+                                               .contains("did you mean `weight`?"));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn add_appends_an_item_with_its_fields_in_canonical_order()
+    {
+        let mut document = gear(ITEMS);
+        document.add(Section::Base, &assignments(&["weight=230", "kind=Stove"]))
+                .unwrap();
+        document.add(Section::Consumables, &assignments(&["kind=Gas", "quantity=2"]))
+                .unwrap();
+
+        assert_eq!(document.document.to_string(),
+                   format!("{}{}\n[[base]]\nkind = \"Stove\"\nweight = 230\n\
+                            \n[[consumables]]\nkind = \"Gas\"\nquantity = 2\n",
+                           META,
+                           ITEMS));
+        document.validate(true).unwrap();
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn add_puts_an_inline_item_on_its_own_line()
+    {
+        let mut document = document("base = [\n    { kind = \"Tent\" },\n]\n");
+        document.add(Section::Base, &assignments(&["kind=Stove"])).unwrap();
+
+        assert_eq!(document.document.to_string(),
+                   "base = [\n    { kind = \"Tent\" },\n    { kind = \"Stove\" },\n]\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn add_needs_an_unused_id()
+    {
+        let mut document = gear(ITEMS);
+
+        assert!(document.add(Section::Consumables, &assignments(&["kind=Duplex"]))
+                        .unwrap_err()
+                        .to_string()
+                        .starts_with("There is already an item with the id `Duplex`"));
+        assert!(document.add(Section::Base, &assignments(&["kind=Tent"])).is_ok());
+        assert!(document.add(Section::Base, &assignments(&["kind=Tent"])).is_err());
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn set_changes_and_removes_fields_keeping_comments()
+    {
+        let mut document = gear(ITEMS);
+        document.set("Duplex", assignments(&["weight=560", "name=", "price=599"]))
+                .unwrap();
+
+        assert_eq!(document.document.to_string(),
+                   format!("{}{}",
+                           META,
+                           ITEMS.replace("name = \"Duplex\"\nweight = 540",
+                                         "weight = 560")
+                                .replace("sealed\n", "sealed\nprice = 599.0\n")));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn set_inserts_new_fields_in_canonical_order()
+    {
+        let mut stove = document("[[base]]\nkind = \"Stove\"\n\
+                                  # measured\nweight = 230\n");
+        stove.set("Stove", assignments(&["url=https://example.com",
+                                         "group=Kitchen",
+                                         "name=Pocket"]))
+             .unwrap();
+
+        let text = stove.document.to_string();
+        assert_eq!(text, "[[base]]\nkind = \"Stove\"\nname = \"Pocket\"\n\
+                          group = \"Kitchen\"\n# measured\nweight = 230\n\
+                          url = \"https://example.com\"\n");
+        stove.format("kind");
+        assert_eq!(stove.document.to_string(), text);
+
+        let mut inline = document("base = [{ kind = \"Stove\", weight = 230 }]\n");
+        inline.set("Stove", assignments(&["quantity=2", "name=Pocket"])).unwrap();
+        assert_eq!(inline.document.to_string(),
+                   "base = [{ kind = \"Stove\", name = \"Pocket\", weight = 230, \
+                    quantity = 2 }]\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn set_and_remove_need_a_single_item_with_the_id()
+    {
+        let mut document = gear(&format!("{}\n[[base]]\nkind = \"Duplex\"\n", ITEMS));
+
+        assert_eq!(document.set("Stove", Vec::new()).unwrap_err().to_string(),
+                   "There is no item with the id `Stove`");
+        assert!(document.remove("Duplex").unwrap_err()
+                                         .to_string()
+                                         .starts_with("There are 2 items"));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn remove_takes_out_the_item()
+    {
+        let mut document = gear(&format!("{}\n[[base]]\nkind = \"Stove\"\n", ITEMS));
+        document.remove("Stove").unwrap();
+
+        assert_eq!(document.document.to_string(), format!("{}{}", META, ITEMS));
+        assert!(document.diff().to_string().contains("-kind = \"Stove\""));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn rename_changes_the_meta_values_the_rules_and_the_items()
+    {
+        let mut document = gear("
+[meta.groups]
+values = [\"Kitchen\", \"Shelter\"]
+
+[[meta.rules.required]]
+attributes = [\"name\"]
+when = { group = \"Kitchen\" }
+
+[[base]]
+kind = \"Stove\"
+name = \"Pocket Rocket\"
+group = \"Kitchen\" # hot
+distances = [\"Short\"]
+");
+
+        assert_eq!(document.rename("group", "Kitchen", "Cooking"), 3);
+        document.validate(true).unwrap();
+        let diff = document.diff().to_string();
+        assert!(diff.contains("+values = [\"Cooking\", \"Shelter\"]"));
+        assert!(diff.contains("+when = { group = \"Cooking\" }"));
+        assert!(diff.contains("+group = \"Cooking\" # hot"));
+        assert!(!document.document.to_string().contains("Kitchen"));
+
+        assert_eq!(document.rename("distance", "Short", "Near"), 1);
+        assert!(document.document.to_string().contains("distances = [\"Near\"]"));
+        assert_eq!(document.rename("temperature", "Cold", "Freezing"), 0);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn rename_merges_into_an_existing_value()
+    {
+        let mut document = document("\
+[meta.distances]
+values = [\"Short\", \"Near\", \"Long\"]
+
+[[base]]
+kind = \"Tent\"
+distances = [\"Short\", \"Near\"]
+");
+
+        assert_eq!(document.rename("distance", "Short", "Near"), 2);
+        let text = document.document.to_string();
+        assert!(text.contains("values = [\"Near\", \"Long\"]"));
+        assert!(text.contains("distances = [\"Near\"]"));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn format_sorts_the_items_their_fields_and_values()
+    {
+        let mut document = document("\
+[meta.groups]
+values = [\"Shelter\",   \"Kitchen\"]
+
+# Cooking
+[[base]]
+weight = 230
+kind = \"Stove\" # titanium
+group = \"Kitchen\"
+distances = [\"Short\", \"Long\"]
+
+# Sleeping
+[[base]]
+kind = \"Bag\"
+group = \"Shelter\"
+");
+        document.format("kind");
+
+        assert_eq!(document.document.to_string(), "\
+[meta.groups]
+values = [\"Kitchen\", \"Shelter\"]
+
+# Sleeping
+[[base]]
+kind = \"Bag\"
+group = \"Shelter\"
+
+# Cooking
+[[base]]
+kind = \"Stove\" # titanium
+group = \"Kitchen\"
+weight = 230
+distances = [\"Long\", \"Short\"]
+");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn format_sorts_by_the_field_with_the_items_missing_it_last()
+    {
+        let mut document = document("\
+consumables = [
+    { kind = \"Water\" },
+    # fuel
+    { kind = \"Gas\", weight = 230 },
+    { weight = 100, kind = \"Food\" }, # dinner
+]
+");
+        document.format("weight");
+        let formatted = document.document.to_string();
+
+        assert_eq!(formatted, "\
+consumables = [
+    { kind = \"Food\", weight = 100 }, # dinner
+    # fuel
+    { kind = \"Gas\", weight = 230 },
+    { kind = \"Water\" },
+]
+");
+
+        /* Formatting is idempotent */
+        document.format("weight");
+        assert_eq!(document.document.to_string(), formatted);
+    }
+}
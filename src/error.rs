@@ -10,6 +10,8 @@ use std::{
 
 use toml::de;
 
+use crate::location::Location;
+
 
 /*----------------------------------------------------------------------------*/
 pub type Result<T> = ::std::result::Result<T, Error>;
@@ -20,8 +22,34 @@ pub type Result<T> = ::std::result::Result<T, Error>;
 pub enum Error
 {
     GearError(String),
+    SourceError(String, Location),
     IoError(io::Error),
-    TomlDeError(de::Error)
+    TomlDeError(de::Error),
+    CsvError(csv::Error),
+    HintedError(Box<Error>, String),
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Error
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn at(self, location: Location) -> Self
+    {
+        match self
+        {
+            Self::GearError(message) => Self::SourceError(message, location),
+            Self::HintedError(error, hint) =>
+                Self::HintedError(Box::new(error.at(location)), hint),
+            error => error,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn hinted(self, hint: &str) -> Self
+    {
+        Self::HintedError(Box::new(self), hint.into())
+    }
 }
 
 
@@ -39,8 +67,12 @@ impl Display for Error
         match self
         {
             GearError(message) => write!(f, "{}", message),
+            SourceError(message, location) =>
+                write!(f, "{}\n{}", message, location),
             IoError(error) => write!(f, "{}", error),
             TomlDeError(error) => write!(f, "{}", error),
+            CsvError(error) => write!(f, "{}", error),
+            HintedError(error, hint) => write!(f, "{}\n{}", error, hint),
         }
     }
 }
@@ -77,3 +109,14 @@ impl From<de::Error> for Error
         Self::TomlDeError(error)
     }
 }
+
+
+/*----------------------------------------------------------------------------*/
+impl From<csv::Error> for Error
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn from(error: csv::Error) -> Self
+    {
+        Self::CsvError(error)
+    }
+}
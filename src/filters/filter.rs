@@ -11,6 +11,7 @@ use crate::{
 
 
 /*----------------------------------------------------------------------------*/
+#[allow(clippy::enum_variant_names)]
 pub enum Filter
 {
     IsInGroups(IsInGroups),
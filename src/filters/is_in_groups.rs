@@ -31,6 +31,6 @@ impl Filter for IsInGroups
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn filter(&self, item: &Item) -> bool
     {
-        item.group().map_or(false, |group| self.groups.contains(group))
+        item.group().is_some_and(|group| self.groups.contains(group))
     }
 }
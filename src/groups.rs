@@ -0,0 +1,28 @@
+use std::collections::BTreeMap;
+
+use crate::input::Item;
+
+
+/*----------------------------------------------------------------------------*/
+pub const UNGROUPED: &str = "Ungrouped";
+
+
+/*----------------------------------------------------------------------------*/
+/* Items by their groups in alphabetical order, keeping the order of the items
+   within the groups, and listing the items without a group last */
+pub fn grouped<'a>(items: &[&'a Item]) -> Vec<(Option<&'a str>, Vec<&'a Item>)>
+{
+    let mut groups = BTreeMap::<Option<&str>, Vec<&Item>>::new();
+    for &item in items
+    {
+        groups.entry(item.group()).or_default().push(item);
+    }
+
+    let mut groups = groups.into_iter().collect::<Vec<_>>();
+    let ungrouped = groups.iter()
+                          .take_while(|(group, _)| group.is_none())
+                          .count();
+    groups.rotate_left(ungrouped);
+
+    groups
+}
@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use crate::input::{
+    Gear,
+    Item,
+};
+
+
+/*----------------------------------------------------------------------------*/
+/* Items are considered heavy when they weigh several times more than the
+   median weight of their group */
+pub struct HeavyItems<'a>
+{
+    medians: BTreeMap<&'a str, i32>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> HeavyItems<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub const FACTOR: i32 = 3;
+    const MIN_GROUP_SIZE: usize = 3;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(gear: &'a Gear) -> Self
+    {
+        let mut weights = BTreeMap::new();
+        for item in gear.items()
+        {
+            if let (Some(group), true) = (item.group(), item.is_present("weight"))
+            {
+                weights.entry(group)
+                       .or_insert_with(Vec::new)
+                       .push(item.weight());
+            }
+        }
+
+        let medians =
+            weights.into_iter()
+                   .filter(|(_, weights)| weights.len() >= Self::MIN_GROUP_SIZE)
+                   .map(|(group, mut weights)|
+                        {
+                            weights.sort_unstable();
+                            (group, weights[weights.len()/2])
+                        })
+                   .collect();
+
+        Self { medians }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn median(&self, item: &Item) -> Option<i32>
+    {
+        item.group().and_then(|group| self.medians.get(group)).copied()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn is_heavy(&self, item: &Item) -> bool
+    {
+        self.median(item).is_some_and(|median| item.weight() > median*Self::FACTOR)
+    }
+}
@@ -0,0 +1,288 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+    Write,
+};
+
+use crate::{
+    input::{
+        Item,
+        Value,
+        Section,
+        Formatters,
+        Shares,
+    },
+    column::Alignment,
+    totals::Totals,
+    groups::{
+        self,
+        grouped,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+h1, h2 { font-weight: normal; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 1em; }
+th, td { padding: 0.3em 0.6em; border-bottom: 1px solid #ddd; }
+th { background: #f4f4f4; text-align: left; }
+table.sortable th { cursor: pointer; user-select: none; }
+table.sortable th[data-order=ascending]::after { content: ' \\25B2'; }
+table.sortable th[data-order=descending]::after { content: ' \\25BC'; }
+td.number, th.number { text-align: right; }
+tfoot td, tr.total td { font-weight: bold; border-top: 2px solid #999; }
+.breakdown { margin-bottom: 2em; }
+.breakdown div { display: flex; align-items: center; margin: 0.2em 0; }
+.breakdown .label { width: 12em; }
+.breakdown .bar { height: 1em; background: #5a8f5a; margin-right: 0.5em; }
+@media print { table.sortable th::after { content: ''; } }
+";
+
+
+/*----------------------------------------------------------------------------*/
+const SCRIPT: &str = "\
+document.querySelectorAll('table.sortable').forEach(function (table) {
+  table.querySelectorAll('thead th').forEach(function (header, index) {
+    header.addEventListener('click', function () {
+      var ascending = header.getAttribute('data-order') !== 'ascending';
+      table.querySelectorAll('thead th').forEach(function (other) {
+        other.removeAttribute('data-order');
+      });
+      header.setAttribute('data-order', ascending ? 'ascending' : 'descending');
+      var body = table.tBodies[0];
+      var rows = Array.prototype.slice.call(body.rows);
+      rows.sort(function (left, right) {
+        var a = left.cells[index].getAttribute('data-value');
+        var b = right.cells[index].getAttribute('data-value');
+        var order = (a !== '' && b !== '' && !isNaN(a) && !isNaN(b))
+                  ? Number(a) - Number(b)
+                  : a.localeCompare(b);
+        return ascending ? order : -order;
+      });
+      rows.forEach(function (row) { body.appendChild(row); });
+    });
+  });
+});
+";
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Html(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Html
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(headers: &[&str],
+               items: &[&Item],
+               formatters: Formatters<'_>,
+               shares: &Shares) -> Self
+    {
+        let groups = grouped(items);
+
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n\
+                       <meta charset=\"utf-8\">\n<title>Gear List</title>\n");
+        write!(html, "<style>\n{}</style>\n", STYLE).unwrap();
+        html.push_str("</head>\n<body>\n<h1>Gear List</h1>\n");
+
+        Self::summary(&mut html, items, &formatters);
+        Self::breakdown(&mut html, items, &groups, &formatters);
+        for (group, items) in groups.iter()
+        {
+            writeln!(html, "<h2>{}</h2>",
+                   escape(group.unwrap_or(groups::UNGROUPED))).unwrap();
+            Self::table(&mut html, headers, items, &formatters, shares);
+        }
+
+        write!(html, "<script>\n{}</script>\n</body>\n</html>\n", SCRIPT).unwrap();
+        Self(html)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn summary(html: &mut String,
+               items: &[&Item],
+               formatters: &Formatters<'_>)
+    {
+        html.push_str("<h2>Totals</h2>\n<table>\n<thead>\n<tr><th></th>");
+        for attribute in Item::NUMERIC_FIELDS.iter()
+        {
+            write!(html, "<th class=\"number\">{}</th>",
+                   escape(&formatters.header(attribute))).unwrap();
+        }
+        html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+        for section in [Section::Base, Section::Consumables].iter()
+        {
+            let items = items.iter()
+                             .copied()
+                             .filter(|item| item.section() == *section)
+                             .collect::<Vec<&Item>>();
+            Self::totals_row(html, &section.to_string(), "", &items, formatters);
+        }
+        Self::totals_row(html, "total", " class=\"total\"", items, formatters);
+
+        html.push_str("</tbody>\n</table>\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn totals_row(html: &mut String,
+                  label: &str,
+                  class: &str,
+                  items: &[&Item],
+                  formatters: &Formatters<'_>)
+    {
+        let totals = Totals::new(items);
+        write!(html, "<tr{}><td>{}</td>", class, escape(&capitalise(label))).unwrap();
+        for attribute in Item::NUMERIC_FIELDS.iter()
+        {
+            write!(html, "<td class=\"number\">{}</td>",
+                   escape(&totals.formatted(attribute, formatters))).unwrap();
+        }
+        html.push_str("</tr>\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn breakdown(html: &mut String,
+                 items: &[&Item],
+                 groups: &[(Option<&str>, Vec<&Item>)],
+                 formatters: &Formatters<'_>)
+    {
+        let total = Totals::new(items).number("weight").as_f64();
+        if total <= 0.0
+        {
+            return;
+        }
+
+        /* The bars share the row with the label and the value, hence they are
+           scaled down to fill at most 60% of the row */
+        html.push_str("<h2>Weight by Group</h2>\n<div class=\"breakdown\">\n");
+        for (group, items) in groups.iter()
+        {
+            let totals = Totals::new(items);
+            let share = 100.0*totals.number("weight").as_f64()/total;
+            writeln!(html,
+                   "<div><span class=\"label\">{}</span>\
+                    <span class=\"bar\" style=\"width: {:.1}%\"></span>\
+                    <span>{} ({:.1}%)</span></div>",
+                   escape(group.unwrap_or(groups::UNGROUPED)),
+                   share*0.6,
+                   escape(&totals.formatted("weight", formatters)),
+                   share).unwrap();
+        }
+        html.push_str("</div>\n");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn table(html: &mut String,
+             headers: &[&str],
+             items: &[&Item],
+             formatters: &Formatters<'_>,
+             shares: &Shares)
+    {
+        let class = |header: &str|
+            match Item::alignment(header)
+            {
+                Alignment::Right => " class=\"number\"",
+                _ => "",
+            };
+
+        html.push_str("<table class=\"sortable\">\n<thead>\n<tr>");
+        for header in headers
+        {
+            write!(html, "<th{}>{}</th>",
+                   class(header), escape(&formatters.header(header))).unwrap();
+        }
+        html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+        for item in items
+        {
+            html.push_str("<tr>");
+            for header in headers
+            {
+                let value =
+                    match item.value(header, shares)
+                    {
+                        None => String::new(),
+                        Some(Value::List(values)) => values.join(" "),
+                        Some(value) => value.to_string(),
+                    };
+                write!(html, "<td{} data-value=\"{}\">{}</td>",
+                       class(header),
+                       escape(&value),
+                       escape(&item.formatted(header, formatters, shares)
+                                   .unwrap_or_default())).unwrap();
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n");
+
+        /* Subtotals of the numeric columns, labelled in the first column */
+        let totals = Totals::new(items);
+        html.push_str("<tfoot>\n<tr>");
+        for (i, header) in headers.iter().enumerate()
+        {
+            let cell =
+                if Item::NUMERIC_FIELDS.contains(header)
+                {
+                    totals.formatted(header, formatters)
+                }
+                else if i == 0
+                {
+                    "Subtotal".into()
+                }
+                else
+                {
+                    String::new()
+                };
+            write!(html, "<td{}>{}</td>", class(header), escape(&cell)).unwrap();
+        }
+        html.push_str("</tr>\n</tfoot>\n</table>\n");
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn capitalise(text: &str) -> String
+{
+    let mut chars = text.chars();
+    chars.next()
+         .map(|first| first.to_uppercase().chain(chars).collect())
+         .unwrap_or_default()
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn escape(text: &str) -> String
+{
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars()
+    {
+        match c
+        {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Html
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
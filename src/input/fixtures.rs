@@ -0,0 +1,25 @@
+use crate::input::{
+    Gear,
+    Source,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub const META: &str = "\
+[meta.weight]
+symbol = \"g\"
+position = \"SUFFIX\"
+
+[meta.price]
+symbol = \"$\"
+position = \"PREFIX\"
+";
+
+
+/*----------------------------------------------------------------------------*/
+/* Gear list of the given items, or other sections, after the units */
+pub fn gear(sections: &str) -> crate::Result<Gear>
+{
+    Gear::from_source(Source::new("gear.toml", format!("{}{}", META, sections)),
+                      true)
+}
@@ -28,6 +28,12 @@ pub struct Unit
 /*----------------------------------------------------------------------------*/
 impl Unit
 {
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn symbol(&self) -> &str
+    {
+        &self.symbol
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn format_to_string<T>(&self, value: T,
                                       buffer: &mut String)
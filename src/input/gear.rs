@@ -1,6 +1,12 @@
-use std::fs::read_to_string;
+use std::{
+    fs::read_to_string,
+    collections::BTreeSet,
+};
 
-use toml::from_str;
+use toml::{
+    Deserializer,
+    Spanned,
+};
 
 use serde::Deserialize;
 
@@ -8,9 +14,17 @@ use crate::{
     input::{
         Meta,
         Item,
+        Source,
+        Section,
+        strict::UnknownKey,
     },
     filters::Filter,
-    validators::Validator,
+    validators::{
+        Validator,
+        PositiveQuantity,
+    },
+    validate::Validate,
+    suggest::suggestion,
 };
 
 
@@ -19,8 +33,11 @@ use crate::{
 pub struct Gear
 {
     meta: Meta,
-    base: Option<Vec<Item>>,
-    consumables: Option<Vec<Item>>,
+    base: Option<Vec<Spanned<Item>>>,
+    consumables: Option<Vec<Spanned<Item>>>,
+
+    #[serde(skip)]
+    source: Source,
 }
 
 
@@ -28,11 +45,48 @@ pub struct Gear
 impl Gear
 {
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
-    pub fn from_toml(file_name: &str) -> crate::Result<Self>
+    pub fn from_toml(file_name: &str,
+                     is_strict: bool) -> crate::Result<Self>
     {
-        let input = read_to_string(file_name)?;
-        let input = from_str(&input)?;
-        Ok(input)
+        Self::from_source(Source::new(file_name, read_to_string(file_name)?),
+                          is_strict)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn from_source(source: Source,
+                       is_strict: bool) -> crate::Result<Self>
+    {
+        /* Collect the keys serde ignored, because they are not part of any of
+           the expected structures */
+        let mut unknown_keys = Vec::new();
+        let deserializer = Deserializer::new(source.text());
+        let mut gear: Self =
+            serde_ignored::deserialize(
+                deserializer,
+                |path| unknown_keys.extend(UnknownKey::new(&path)))
+            .map_err(|error| source.parse_error(error))?;
+
+        if is_strict
+        {
+            if let Some(unknown_key) = unknown_keys.into_iter().next()
+            {
+                return Err(unknown_key.into_error(&source));
+            }
+        }
+
+        gear.source = source;
+
+        for item in gear.base.iter_mut().flatten()
+        {
+            item.get_mut().set_section(Section::Base);
+        }
+
+        for item in gear.consumables.iter_mut().flatten()
+        {
+            item.get_mut().set_section(Section::Consumables);
+        }
+
+        Ok(gear)
     }
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
@@ -41,6 +95,233 @@ impl Gear
         &self.meta
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn source(&self) -> &Source
+    {
+        &self.source
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn spanned_items(&self) -> impl Iterator<Item = &'_ Spanned<Item>>
+    {
+        self.base.iter()
+                 .chain(self.consumables.iter())
+                 .flatten()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn items(&self) -> impl Iterator<Item = &'_ Item>
+    {
+        self.spanned_items().map(Spanned::get_ref)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn known_values<'a, I>(declared: Option<I>,
+                           used: impl Iterator<Item = &'a str>)
+        -> BTreeSet<&'a str>
+        where I: Iterator<Item = &'a str>
+    {
+        match declared
+        {
+            Some(declared) => declared.collect(),
+            None => used.collect(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn unknown<'f>(attribute: &str,
+                   values: impl Iterator<Item = &'f str>,
+                   known: &BTreeSet<&str>,
+                   expected: Option<String>) -> Vec<String>
+    {
+        values.filter(|value| !known.contains(value))
+              .map(
+                  |value|
+                  {
+                      let mut message = format!("Unknown {} `{}`", attribute, value);
+                      if let Some(expected) = expected.as_ref()
+                      {
+                          message.push_str(&format!(", expected {}", expected));
+                      }
+                      if let Some(suggestion) = suggestion(value, known.iter().copied())
+                      {
+                          message.push_str(&format!(", did you mean `{}`?", suggestion));
+                      }
+                      message
+                  })
+              .collect()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn unknown_values<'f, I>(&self, groups: Option<I>,
+                                        distances: Option<I>,
+                                        temperatures: Option<I>) -> Vec<String>
+        where I: Iterator<Item = &'f str>
+    {
+        let mut unknown = Vec::new();
+
+        if let Some(groups) = groups
+        {
+            let known = Self::known_values(
+                self.meta.groups().map(|groups| groups.values()),
+                self.items().filter_map(Item::group));
+            unknown.extend(Self::unknown("group", groups, &known, None));
+        }
+
+        if let Some(distances) = distances
+        {
+            let known = Self::known_values(
+                self.meta.distances().map(|distances| distances.values()),
+                self.items().flat_map(Item::distances));
+            unknown.extend(Self::unknown("distance", distances, &known, None));
+        }
+
+        if let Some(temperatures) = temperatures
+        {
+            let known = Self::known_values(
+                self.meta.temperatures().map(|temperatures| temperatures.values()),
+                self.items().flat_map(Item::temperatures));
+            unknown.extend(Self::unknown("temperature", temperatures, &known, None));
+        }
+
+        unknown
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn validate_id(&self, id: &str) -> crate::Result<()>
+    {
+        match self.items().filter(|item| item.id() == id).count()
+        {
+            0 => (),
+            1 => return Ok(()),
+            count => return Err(format!("There are {} items with the id `{}`, \
+                                         give them different names to tell \
+                                         them apart",
+                                        count,
+                                        id).into()),
+        }
+
+        let mut message = format!("There is no item with the id `{}`", id);
+        if let Some(suggestion) = suggestion(id, self.items().map(Item::id))
+        {
+            message.push_str(&format!(", did you mean `{}`?", suggestion));
+        }
+        message.push_str("\nThe id of an item is its name, or its kind if it \
+                          has no name");
+
+        Err(message.into())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn columns<'a, I>(&'a self, columns: Option<I>)
+        -> crate::Result<Vec<&'a str>>
+        where I: Iterator<Item = &'a str>
+    {
+        /* Without explicit columns, use the `default` preset if there is one,
+           or all the item fields otherwise */
+        let columns = match columns
+        {
+            Some(columns) => columns.collect(),
+            None => match self.meta.preset("default")
+            {
+                Some(_) => vec!["@default"],
+                None => return Ok(Item::FIELDS.to_vec()),
+            },
+        };
+
+        let mut resolved = Vec::with_capacity(columns.len());
+        for column in columns
+        {
+            if let Some(name) = column.strip_prefix('@')
+            {
+                match self.meta.preset(name)
+                {
+                    Some(preset) =>
+                        resolved.extend(preset.iter().map(String::as_str)),
+                    None =>
+                    {
+                        let mut message =
+                            format!("Unknown column preset `@{}`", name);
+                        if let Some(suggestion) = suggestion(name, self.meta.presets())
+                        {
+                            message.push_str(
+                                &format!(", did you mean `@{}`?", suggestion));
+                        }
+
+                        return Err(message.into());
+                    },
+                }
+            }
+            else if Item::COLUMNS.contains(&column)
+            {
+                resolved.push(column);
+            }
+            else
+            {
+                let mut message = format!("Unknown column `{}`", column);
+                if let Some(suggestion) = suggestion(column,
+                                                     Item::COLUMNS.iter().copied())
+                {
+                    message.push_str(&format!(", did you mean `{}`?", suggestion));
+                }
+
+                return Err(message.into());
+            }
+        }
+
+        /* The table could not lay out rows without a single column */
+        if resolved.is_empty()
+        {
+            return Err(String::from("No columns to show").into());
+        }
+
+        Ok(resolved)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn validate_filters<'f, I>(&self, groups: Option<I>,
+                                          distances: Option<I>,
+                                          temperatures: Option<I>)
+        -> crate::Result<()>
+        where I: Iterator<Item = &'f str>
+    {
+        let mut unknown = Vec::new();
+
+        if let (Some(groups), Some(declared)) = (groups, self.meta.groups())
+        {
+            let known = declared.values().collect();
+            let expected = Some(declared.expected());
+            unknown.extend(Self::unknown("group", groups, &known, expected));
+        }
+
+        if let (Some(distances), Some(declared)) = (distances,
+                                                    self.meta.distances())
+        {
+            let known = declared.values().collect();
+            let expected = Some(declared.expected());
+            unknown.extend(Self::unknown("distance", distances, &known, expected));
+        }
+
+        if let (Some(temperatures), Some(declared)) = (temperatures,
+                                                       self.meta.temperatures())
+        {
+            let known = declared.values().collect();
+            let expected = Some(declared.expected());
+            unknown.extend(Self::unknown("temperature", temperatures, &known, expected));
+        }
+
+        if unknown.is_empty()
+        {
+            Ok(())
+        }
+        else
+        {
+            unknown.push("Use `--allow-unknown` to query values not declared \
+                          in `meta` anyway".into());
+            Err(unknown.join("\n").into())
+        }
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn filters<'f, I>(groups: Option<I>,
                       distances: Option<I>,
@@ -70,9 +351,9 @@ impl Gear
     }
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
-    fn validators(&self) -> Vec<Validator<'_>>
+    pub fn validators(&self) -> Vec<Validator<'_>>
     {
-        let mut validators = Vec::new();
+        let mut validators = vec![Validator::from(&PositiveQuantity)];
 
         if let Some(groups) = self.meta.groups()
         {
@@ -89,9 +370,44 @@ impl Gear
             validators.push(temperatures.into());
         }
 
+        if let Some(rules) = self.meta.rules()
+        {
+            validators.extend(rules.required().iter().map(Validator::from));
+            validators.extend(rules.bounds().iter().map(Validator::from));
+        }
+
         validators
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn validate(&self, validators: &[Validator<'_>],
+                       item: &Spanned<Item>) -> crate::Result<()>
+    {
+        validators.iter().try_for_each(
+            |validator|
+                validator.validate(item.get_ref()).map_err(
+                    |error|
+                    {
+                        let attribute = validator.attribute();
+                        let location =
+                            self.source.attribute_location(item.span(),
+                                                           &attribute);
+                        error.at(location)
+                    }))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn validate_items(&self) -> crate::Result<()>
+    {
+        let validators = self.validators();
+        for item in self.spanned_items()
+        {
+            self.validate(&validators, item)?;
+        }
+
+        Ok(())
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn filter_and_validate<'f, I>(&self, is_all: bool,
                                              is_base: bool,
@@ -111,11 +427,10 @@ impl Gear
             if let Some(base) = self.base.as_ref()
             {
                 for item in base.iter()
-                                .filter(|item| item.filter(&filters))
+                                .filter(|item| item.get_ref().filter(&filters))
                 {
-                    validators.iter()
-                              .try_for_each(|validator| validator.validate(item))?;
-                    results.push(item);
+                    self.validate(&validators, item)?;
+                    results.push(item.get_ref());
                 }
             }
         }
@@ -125,11 +440,10 @@ impl Gear
             if let Some(consumables) = self.consumables.as_ref()
             {
                 for item in consumables.iter()
-                                       .filter(|item| item.filter(&filters))
+                                       .filter(|item| item.get_ref().filter(&filters))
                 {
-                    validators.iter()
-                              .try_for_each(|validator| validator.validate(item))?;
-                    results.push(item);
+                    self.validate(&validators, item)?;
+                    results.push(item.get_ref());
                 }
             }
         }
@@ -137,3 +451,100 @@ impl Gear
         Ok(results)
     }
 }
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::input::fixtures;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn with_presets(presets: &str) -> crate::Result<Gear>
+    {
+        fixtures::gear(&format!("\n[meta.columns]\n{}", presets))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn columns<'a>(gear: &'a Gear,
+                   columns: Option<&[&'a str]>) -> crate::Result<Vec<&'a str>>
+    {
+        gear.columns(columns.map(|columns| columns.iter().copied()))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn columns_default_to_the_default_preset_or_all_the_fields()
+    {
+        let gear = with_presets("").unwrap();
+        assert_eq!(columns(&gear, None).unwrap(), Item::FIELDS.to_vec());
+        assert_eq!(columns(&gear, Some(&["quantity"])).unwrap(), vec!["quantity"]);
+
+        let gear = with_presets("default = [\"kind\", \"weight%\"]").unwrap();
+        assert_eq!(columns(&gear, None).unwrap(), vec!["kind", "weight%"]);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn columns_expand_the_presets_in_place()
+    {
+        let gear = with_presets("short = [\"kind\", \"weight\"]").unwrap();
+        assert_eq!(columns(&gear, Some(&["name", "@short", "price"])).unwrap(),
+                   vec!["name", "kind", "weight", "price"]);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn columns_suggest_the_closest_preset_or_column()
+    {
+        let gear = with_presets("short = [\"kind\"]").unwrap();
+        assert_eq!(columns(&gear, Some(&["@shrot"])).unwrap_err().to_string(),
+                   "Unknown column preset `@shrot`, did you mean `@short`?");
+        assert_eq!(columns(&gear, Some(&["wieght"])).unwrap_err().to_string(),
+                   "Unknown column `wieght`, did you mean `weight`?");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn presets_must_have_known_columns()
+    {
+        let error = with_presets("short = [\"kind\", \"wieght\"]").err().unwrap();
+        assert!(error.to_string().contains("did you mean `weight`?"));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn empty_presets_and_column_lists_are_rejected()
+    {
+        let error = with_presets("empty = []").err().unwrap();
+        assert!(error.to_string().starts_with("column preset `empty` is empty\n"));
+
+        let gear = with_presets("").unwrap();
+        assert_eq!(columns(&gear, Some(&[])).unwrap_err().to_string(),
+                   "No columns to show");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn ids_must_match_exactly_one_item()
+    {
+        let gear = fixtures::gear("
+[[base]]
+kind = \"Tent\"
+
+[[base]]
+kind = \"Stakes\"
+
+[[consumables]]
+kind = \"Stakes\"
+").unwrap();
+
+        assert!(gear.validate_id("Tent").is_ok());
+        assert!(gear.validate_id("Stakes").unwrap_err().to_string()
+                    .starts_with("There are 2 items with the id `Stakes`"));
+        assert!(gear.validate_id("Tnet").unwrap_err().to_string()
+                    .starts_with("There is no item with the id `Tnet`, \
+                                  did you mean `Tent`?"));
+    }
+}
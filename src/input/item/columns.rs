@@ -4,6 +4,7 @@ use crate::{
     input::{
         Item,
         meta::Formatters,
+        item::Shares,
     },
     column::Column,
 };
@@ -15,6 +16,7 @@ pub struct Columns<'a>
     item: &'a Item,
     columns: Iter<'a, &'a str>,
     formatters: &'a Formatters<'a>,
+    shares: &'a Shares,
 }
 
 
@@ -24,13 +26,15 @@ impl<'a> Columns<'a>
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn new(item: &'a Item,
                columns: &'a [&'a str],
-               formatters: &'a Formatters<'a>) -> Self
+               formatters: &'a Formatters<'a>,
+               shares: &'a Shares) -> Self
     {
         Self
         {
             item,
             columns: columns.iter(),
             formatters,
+            shares,
         }
     }
 }
@@ -45,6 +49,7 @@ impl<'a> Iterator for Columns<'a>
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn next(&mut self) -> Option<Self::Item>
     {
-        self.columns.next().map(|name| self.item.column(name, self.formatters))
+        self.columns.next().map(
+            |name| self.item.column(name, self.formatters, self.shares))
     }
 }
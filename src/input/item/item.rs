@@ -15,7 +15,13 @@ use crate::{
     },
     input::{
         Formatters,
-        item::Columns,
+        Section,
+        item::{
+            Columns,
+            Number,
+            Value,
+            Shares,
+        },
     },
     validators::Validity,
 };
@@ -34,6 +40,12 @@ pub struct Item
     distances: Option<HashSet<String>>,
     temperatures: Option<HashSet<String>>,
 
+    quantity: Option<u32>,
+    url: Option<String>,
+    worn: Option<bool>,
+
+    #[serde(skip)]
+    section: Section,
     #[serde(skip)]
     ordered_distances: Cell<Option<Vec<String>>>,
     #[serde(skip)]
@@ -53,6 +65,24 @@ impl Item
                                            "distances",
                                            "temperatures"];
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The fields and the columns computed from the listed items (see `Shares`) */
+    pub const COLUMNS: [&'static str; 11] = ["kind",
+                                             "name",
+                                             "group",
+                                             "weight",
+                                             "price",
+                                             "quantity",
+                                             "distances",
+                                             "temperatures",
+                                             "weight%",
+                                             "price%",
+                                             "group_weight%"];
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub const NUMERIC_FIELDS: [&'static str; 2] = ["weight",
+                                                   "price"];
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub const fn default_field() -> &'static str
     {
@@ -65,10 +95,64 @@ impl Item
         self.name.as_ref().unwrap_or(&self.kind).as_str()
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn kind(&self) -> &str
+    {
+        &self.kind
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn name(&self) -> Option<&str>
+    {
+        self.name.as_deref()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn section(&self) -> Section
+    {
+        self.section
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub(in crate::input) fn set_section(&mut self, section: Section)
+    {
+        self.section = section;
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn is_present(&self, attribute: &str) -> bool
+    {
+        match attribute
+        {
+            "kind" => true,
+            "name" => self.name.is_some(),
+            "group" => self.group.is_some(),
+            "weight" => self.weight.is_some(),
+            "price" => self.price.is_some(),
+            "quantity" => self.quantity.is_some(),
+            "distances" =>
+                self.distances.as_ref().is_some_and(|values| !values.is_empty()),
+            "temperatures" =>
+                self.temperatures.as_ref().is_some_and(|values| !values.is_empty()),
+            _ => unreachable!(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn number(&self, attribute: &str) -> Option<Number>
+    {
+        match attribute
+        {
+            "weight" => self.weight.map(Number::Integer),
+            "price" => self.price.map(Number::Float),
+            _ => unreachable!(),
+        }
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn group(&self) -> Option<&str>
     {
-        self.group.as_ref().map(String::as_str)
+        self.group.as_deref()
     }
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
@@ -100,11 +184,34 @@ impl Item
         self.price.unwrap_or_default()
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn quantity(&self) -> u32
+    {
+        self.quantity.unwrap_or(1)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn url(&self) -> Option<&str>
+    {
+        self.url.as_deref()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn is_worn(&self) -> bool
+    {
+        self.worn.unwrap_or_default()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn distances(&self) -> impl Iterator<Item = &str>
+    {
+        self.distances.iter().flatten().map(String::as_str)
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn is_distance(&self, distance: &str) -> bool
     {
-        self.distances.as_ref().map_or(
-            false,
+        self.distances.as_ref().is_some_and(
             |distances| distances.contains(distance))
     }
 
@@ -125,11 +232,16 @@ impl Item
                                 .map_or(Valid, Invalid) })
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn temperatures(&self) -> impl Iterator<Item = &str>
+    {
+        self.temperatures.iter().flatten().map(String::as_str)
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn is_temperature(&self, temperature: &str) -> bool
     {
-        self.temperatures.as_ref().map_or(
-            false,
+        self.temperatures.as_ref().is_some_and(
             |temperatures| temperatures.contains(temperature))
     }
 
@@ -152,70 +264,107 @@ impl Item
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn columns<'a>(&'a self, columns: &'a [&'a str],
-                                 formatters: &'a Formatters<'a>) -> Columns<'a>
+                                 formatters: &'a Formatters<'a>,
+                                 shares: &'a Shares) -> Columns<'a>
     {
-        Columns::new(self, columns, formatters)
+        Columns::new(self, columns, formatters, shares)
     }
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
-    pub(super) fn column(&self, column: &str,
-                                formatters: &Formatters<'_>) -> Column
+    pub fn alignment(column: &str) -> Alignment
+    {
+        match column
+        {
+            "weight" | "price" | "quantity"
+            | "weight%" | "price%" | "group_weight%" => Alignment::Right,
+            _ => Alignment::Left,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The lower the priority the sooner the column is shrunk when the table
+       does not fit into the available width */
+    pub fn priority(column: &str) -> usize
+    {
+        match column
+        {
+            "temperatures" => 0,
+            "distances" => 1,
+            "group" => 2,
+            "quantity" => 3,
+            "group_weight%" => 4,
+            "price%" => 5,
+            "weight%" => 6,
+            "name" => 7,
+            "kind" => 8,
+            "price" => 9,
+            "weight" => 10,
+            _ => unreachable!(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn value(&self, attribute: &str, shares: &Shares) -> Option<Value<'_>>
     {
-        use Alignment::*;
-        let (alignment, content) =
-            match column
+        if let "weight%" | "price%" | "group_weight%" = attribute
+        {
+            return shares.of(self, attribute)
+                         .map(|share| Value::Number(Number::Float(share)));
+        }
+        else if !self.is_present(attribute)
+        {
+            return None;
+        }
+
+        let value =
+            match attribute
             {
-                "kind" => (Left, Some(self.kind.clone())),
-                "name" => (Left, self.name.as_ref().map(String::clone)),
-                "group" => (Left, self.group.as_ref().map(String::clone)),
-                "weight" =>
-                {
-                    let weight = self.weight.map(
-                        |weight|
-                        {
-                            let mut formatted = String::new();
-                            formatters.weight.format_to_string(weight, &mut formatted);
-                            formatted
-                        });
-
-                    (Right, weight)
-                },
-                "price" =>
-                {
-                    let price = self.price.map(
-                        |price|
-                        {
-                            let mut formatted = String::new();
-                            formatters.price.format_to_string(price, &mut formatted);
-                            formatted
-                        });
-
-                    (Right, price)
-                },
-                "distances" =>
-                {
-                    let distances = self.distances.as_ref().map_or(
-                        None,
-                        |distances|
-                            if distances.is_empty() { None }
-                            else { Some(self.ordered_distances().join(" / ")) });
-
-                    (Left, distances)
-                },
-                "temperatures" =>
-                {
-                    let temperatures = self.temperatures.as_ref().map_or(
-                        None,
-                        |temperatures|
-                            if temperatures.is_empty() { None }
-                            else { Some(self.ordered_temperatures().join(" / ")) });
-
-                    (Left, temperatures)
-                },
+                "kind" => Value::Text(&self.kind),
+                "name" => Value::Text(self.name.as_ref().unwrap()),
+                "group" => Value::Text(self.group.as_ref().unwrap()),
+                "weight" | "price" => Value::Number(self.number(attribute).unwrap()),
+                "quantity" => Value::Number(Number::Integer(self.quantity() as i32)),
+                "distances" => Value::List(self.ordered_distances()),
+                "temperatures" => Value::List(self.ordered_temperatures()),
                 _ => unreachable!(),
             };
 
-        Column::new(alignment, content.into())
+        Some(value)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn formatted(&self, attribute: &str,
+                            formatters: &Formatters<'_>,
+                            shares: &Shares) -> Option<String>
+    {
+        let unit =
+            match attribute
+            {
+                "weight" => formatters.weight,
+                "price" => formatters.price,
+                "weight%" | "price%" | "group_weight%" =>
+                    return shares.of(self, attribute)
+                                 .map(|share| format!("{:.1}%", share)),
+                _ => return self.value(attribute, shares)
+                                .map(|value| value.to_string()),
+            };
+
+        self.value(attribute, shares).map(
+            |value|
+            {
+                let mut formatted = String::new();
+                unit.format_to_string(value, &mut formatted);
+                formatted
+            })
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub(super) fn column(&self, column: &str,
+                                formatters: &Formatters<'_>,
+                                shares: &Shares) -> Column
+    {
+        Column::new(Self::alignment(column),
+                    self.formatted(column, formatters, shares).into())
     }
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
@@ -251,7 +400,7 @@ impl Item
     }
 
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
-    pub fn filter(&self, filters: &Vec<filters::Filter>) -> bool
+    pub fn filter(&self, filters: &[filters::Filter]) -> bool
     {
         filters.iter().all(|filter| filter.filter(self))
     }
@@ -266,6 +415,7 @@ impl Item
             "group" => Self::compare_by_group,
             "weight" => Self::compare_by_weight,
             "price" => Self::compare_by_price,
+            "quantity" => Self::compare_by_quantity,
             "distances" => Self::compare_by_distances,
             "temperatures" => Self::compare_by_temperatures,
             _ => unreachable!(),
@@ -302,6 +452,12 @@ impl Item
         self.price.partial_cmp(&other.price).unwrap_or(Ordering::Equal)
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn compare_by_quantity(&self, other: &Self) -> Ordering
+    {
+        self.quantity().cmp(&other.quantity())
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn compare_by_distances(&self, other: &Self) -> Ordering
     {
@@ -1,5 +1,12 @@
+#[allow(clippy::module_inception)]
 mod item;
 mod columns;
+mod number;
+mod value;
+mod shares;
 
 pub use item::Item;
 pub use columns::Columns;
+pub use number::Number;
+pub use value::Value;
+pub use shares::Shares;
@@ -0,0 +1,68 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use serde::{
+    Serialize,
+    Serializer,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy)]
+pub enum Number
+{
+    Integer(i32),
+    Float(f32),
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Number
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn as_f64(self) -> f64
+    {
+        use Number::*;
+        match self
+        {
+            Integer(value) => value.into(),
+            Float(value) => value.into(),
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Serialize for Number
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        use Number::*;
+        match self
+        {
+            Integer(value) => serializer.serialize_i32(*value),
+            Float(value) => serializer.serialize_f32(*value),
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Number
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        use Number::*;
+        match self
+        {
+            Integer(value) => write!(f, "{}", value),
+            Float(value) => write!(f, "{}", value),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+};
+
+use crate::{
+    input::Item,
+    totals::Totals,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy)]
+struct Share
+{
+    weight: f32,
+    price: f32,
+    group_weight: f32,
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Percentages of the items' weights and prices relative to the items they are
+   listed with, where the items are told apart by their addresses */
+pub struct Shares(HashMap<*const Item, Share>);
+
+
+/*----------------------------------------------------------------------------*/
+impl Shares
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(items: &[&Item]) -> Self
+    {
+        let totals = Totals::new(items);
+        let group_totals =
+            {
+                let mut groups = HashMap::<Option<&str>, Vec<&Item>>::new();
+                for &item in items
+                {
+                    groups.entry(item.group()).or_default().push(item);
+                }
+
+                groups.into_iter()
+                      .map(|(group, items)| (group, Totals::new(&items)))
+                      .collect::<HashMap<_, _>>()
+            };
+
+        Self(items.iter()
+                  .map(|&item|
+                       {
+                           let group_totals = &group_totals[&item.group()];
+                           let share =
+                               Share
+                               {
+                                   weight: share(item, "weight", &totals),
+                                   price: share(item, "price", &totals),
+                                   group_weight: share(item, "weight", group_totals),
+                               };
+                           (item as *const Item, share)
+                       })
+                  .collect())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn of(&self, item: &Item, column: &str) -> Option<f32>
+    {
+        let share = self.0.get(&(item as *const Item))?;
+        match column
+        {
+            "weight%" if item.is_present("weight") => Some(share.weight),
+            "price%" if item.is_present("price") => Some(share.price),
+            "group_weight%"
+                if item.is_present("weight") && item.is_present("group") =>
+                    Some(share.group_weight),
+            "weight%" | "price%" | "group_weight%" => None,
+            _ => unreachable!(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn compare(&self, left: &Item, right: &Item, column: &str) -> Ordering
+    {
+        self.of(left, column).partial_cmp(&self.of(right, column))
+                             .unwrap_or(Ordering::Equal)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn share(item: &Item, attribute: &str, totals: &Totals) -> f32
+{
+    let total = totals.number(attribute).as_f64();
+    let value = item.number(attribute).map_or(0.0, |number| number.as_f64());
+    if total == 0.0
+    {
+        0.0
+    }
+    else
+    {
+        (100.0*value*item.quantity() as f64/total) as f32
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::input::fixtures;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const ITEMS: &str = "
+[[base]]
+kind = \"Tent\"
+group = \"Shelter\"
+weight = 600
+price = 300.0
+
+[[base]]
+kind = \"Stakes\"
+group = \"Shelter\"
+weight = 50
+quantity = 4
+
+[[base]]
+kind = \"Map\"
+price = 10.0
+";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn shares_are_relative_to_the_listed_items()
+    {
+        let gear = fixtures::gear(ITEMS).unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+        let shares = Shares::new(&items);
+
+        assert_eq!(shares.of(items[0], "weight%"), Some(75.0));
+        assert_eq!(shares.of(items[1], "weight%"), Some(25.0));
+        assert_eq!(shares.of(items[1], "group_weight%"), Some(25.0));
+        assert_eq!(shares.of(items[2], "price%").map(f32::round), Some(3.0));
+
+        let shares = Shares::new(&items[1..]);
+        assert_eq!(shares.of(items[1], "weight%"), Some(100.0));
+        assert_eq!(shares.of(items[0], "weight%"), None);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn items_without_the_attribute_have_no_share()
+    {
+        let gear = fixtures::gear(ITEMS).unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+        let shares = Shares::new(&items);
+
+        assert_eq!(shares.of(items[1], "price%"), None);
+        assert_eq!(shares.of(items[2], "weight%"), None);
+        assert_eq!(shares.of(items[2], "group_weight%"), None);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn items_compare_by_their_shares()
+    {
+        let gear = fixtures::gear(ITEMS).unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+        let shares = Shares::new(&items);
+
+        assert_eq!(shares.compare(items[0], items[1], "weight%"), Ordering::Greater);
+        assert_eq!(shares.compare(items[2], items[0], "weight%"), Ordering::Less);
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use serde::{
+    Serialize,
+    Serializer,
+};
+
+use crate::input::item::Number;
+
+
+/*----------------------------------------------------------------------------*/
+pub enum Value<'a>
+{
+    Text(&'a str),
+    Number(Number),
+    List(&'a [String]),
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Display for Value<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        use Value::*;
+        match self
+        {
+            Text(text) => write!(f, "{}", text),
+            Number(number) => write!(f, "{}", number),
+            List(list) => write!(f, "{}", list.join(" / ")),
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Serialize for Value<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        use Value::*;
+        match self
+        {
+            Text(text) => serializer.serialize_str(text),
+            Number(number) => number.serialize(serializer),
+            List(list) => list.serialize(serializer),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 use crate::{
@@ -6,6 +8,14 @@ use crate::{
         GroupValues,
         DistancesValues,
         TemperaturesValues,
+        Rules,
+        deserialize_labels,
+        deserialize_presets,
+    },
+    lints::{
+        Check,
+        Severity,
+        deserialize_severities,
     },
 };
 
@@ -15,6 +25,20 @@ pub struct Formatters<'a>
 {
     pub weight: &'a Unit,
     pub price: &'a Unit,
+    pub labels: &'a HashMap<String, String>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Formatters<'_>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn header(&self, column: &str) -> String
+    {
+        self.labels.get(column)
+                   .cloned()
+                   .unwrap_or_else(|| column.to_uppercase())
+    }
 }
 
 
@@ -27,6 +51,13 @@ pub struct Meta
     groups: Option<GroupValues>,
     distances: Option<DistancesValues>,
     temperatures: Option<TemperaturesValues>,
+    rules: Option<Rules>,
+    #[serde(default, deserialize_with = "deserialize_severities")]
+    lint: HashMap<Check, Severity>,
+    #[serde(default, deserialize_with = "deserialize_labels")]
+    labels: HashMap<String, String>,
+    #[serde(default, deserialize_with = "deserialize_presets")]
+    columns: HashMap<String, Vec<String>>,
 }
 
 
@@ -39,17 +70,10 @@ impl Meta
         &self.weight
     }
 
-    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
-    pub fn price(&self) -> &Unit
-    {
-        &self.price
-    }
-
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn groups(&self) -> Option<&GroupValues>
     {
-        self.groups.as_ref().map_or(
-            None,
+        self.groups.as_ref().and_then(
             |groups|
                 if groups.is_empty() { None }
                 else { Some(groups) })
@@ -58,8 +82,7 @@ impl Meta
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn distances(&self) -> Option<&DistancesValues>
     {
-        self.distances.as_ref().map_or(
-            None,
+        self.distances.as_ref().and_then(
             |distances|
                 if distances.is_empty() { None }
                 else { Some(distances) })
@@ -68,13 +91,38 @@ impl Meta
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn temperatures(&self) -> Option<&TemperaturesValues>
     {
-        self.temperatures.as_ref().map_or(
-            None,
+        self.temperatures.as_ref().and_then(
             |temperatures|
                 if temperatures.is_empty() { None }
                 else { Some(temperatures) })
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn rules(&self) -> Option<&Rules>
+    {
+        self.rules.as_ref()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn severity(&self, check: Check) -> Severity
+    {
+        self.lint.get(&check)
+                 .copied()
+                 .unwrap_or_else(|| check.default_severity())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn preset(&self, name: &str) -> Option<&[String]>
+    {
+        self.columns.get(name).map(Vec::as_slice)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn presets(&self) -> impl Iterator<Item = &str>
+    {
+        self.columns.keys().map(String::as_str)
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     pub fn formatters(&self) -> Formatters<'_>
     {
@@ -82,6 +130,7 @@ impl Meta
         {
             weight: &self.weight,
             price: &self.price,
+            labels: &self.labels,
         }
     }
 }
@@ -2,10 +2,22 @@ mod gear;
 mod item;
 mod meta;
 mod formatters;
+mod source;
+mod section;
+mod strict;
+#[cfg(test)]
+pub mod fixtures;
 
 
 pub use gear::Gear;
-pub use item::Item;
+pub use item::{
+    Item,
+    Number,
+    Value,
+    Shares,
+};
+pub use source::Source;
+pub use section::Section;
 // HACK: It looks like this is a bug in `rustc`, for some reason the preferred
 //       `pub use meta::Meta` produces the E0432 compilation error
 pub use self::meta::{
@@ -0,0 +1,34 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use serde::Deserialize;
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Section
+{
+    #[default]
+    Base,
+    Consumables,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Section
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        use Section::*;
+        match self
+        {
+            Base => write!(f, "base"),
+            Consumables => write!(f, "consumables"),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::ops::Range;
+
+use toml::de;
+
+use crate::{
+    Error,
+    location::Location,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Default)]
+pub struct Source
+{
+    path: String,
+    text: String,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Source
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(path: &str,
+               text: String) -> Self
+    {
+        Self
+        {
+            path: path.into(),
+            text,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn text(&self) -> &str
+    {
+        &self.text
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn location(&self, span: Range<usize>) -> Location
+    {
+        let start = span.start.min(self.text.len());
+        let line_start = self.text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.text[start..].find('\n')
+                                         .map_or(self.text.len(), |i| start + i);
+        let snippet = self.text[line_start..line_end].trim_end();
+
+        let line = self.text[..line_start].matches('\n').count() + 1;
+        let column = self.text[line_start..start].chars().count() + 1;
+        let width = self.text[start..span.end.clamp(start, line_end)].trim_end()
+                                                                     .chars()
+                                                                     .count();
+
+        Location::new(&self.path, line, column, snippet, width)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn attribute_location(&self, span: Range<usize>,
+                                     attribute: &str) -> Location
+    {
+        match self.attribute_span(span.clone(), attribute)
+        {
+            Some(attribute_span) => self.location(attribute_span),
+            None => self.location(span),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn attribute_span(&self, span: Range<usize>,
+                             attribute: &str) -> Option<Range<usize>>
+    {
+        let owner = self.text.get(span.clone())?;
+        owner.match_indices(attribute).find_map(
+            |(i, _)|
+            {
+                let is_key_start =
+                    owner[..i].chars()
+                              .next_back()
+                              .is_none_or(|c| c.is_whitespace() || c == '{'
+                                                                || c == ',');
+                let rest = &owner[i + attribute.len()..];
+                let is_key_end = rest.trim_start_matches([' ', '\t'])
+                                     .starts_with('=');
+
+                if is_key_start && is_key_end
+                {
+                    let start = span.start + i;
+                    let end = rest.find('\n')
+                                  .map_or(span.end,
+                                          |j| start + attribute.len() + j);
+                    Some(start..end)
+                }
+                else
+                {
+                    None
+                }
+            })
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn parse_error(&self, error: de::Error) -> Error
+    {
+        match error.span()
+        {
+            Some(span) => Error::SourceError(error.message().trim_end().into(),
+                                             self.location(span)),
+            None => error.into(),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::ops::Range;
+
+use serde::{
+    de::{
+        self,
+        value,
+        Visitor,
+    },
+    forward_to_deserialize_any,
+    Deserialize,
+    Deserializer,
+};
+use serde_ignored::Path;
+use toml_edit::ImDocument;
+
+use crate::{
+    Error,
+    input::{
+        Gear,
+        Meta,
+        Item,
+        Source,
+        formatters::Unit,
+    },
+    validators::{
+        GroupValues,
+        DistancesValues,
+        TemperaturesValues,
+        Rules,
+        RequiredAttributes,
+        NumericBounds,
+        Condition,
+    },
+    lints::Check,
+    suggest::suggestion,
+};
+
+
+/*----------------------------------------------------------------------------*/
+/* Deserializer which collects the names of the fields a type expects, or the
+   variants of an enum, without actually deserializing anything */
+struct Fields(Option<&'static [&'static str]>);
+
+
+/*----------------------------------------------------------------------------*/
+impl<'de> Deserializer<'de> for &mut Fields
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    type Error = value::Error;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+        where V: Visitor<'de>
+    {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn deserialize_struct<V>(self, _name: &'static str,
+                                   fields: &'static [&'static str],
+                                   _visitor: V) -> Result<V::Value, Self::Error>
+        where V: Visitor<'de>
+    {
+        self.0 = Some(fields);
+        Err(de::Error::custom("fields collected"))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn deserialize_enum<V>(self, _name: &'static str,
+                                 variants: &'static [&'static str],
+                                 _visitor: V) -> Result<V::Value, Self::Error>
+        where V: Visitor<'de>
+    {
+        self.0 = Some(variants);
+        Err(de::Error::custom("variants collected"))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    forward_to_deserialize_any!
+    {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map identifier ignored_any
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn fields_of<'de, T>() -> &'static [&'static str]
+    where T: Deserialize<'de>
+{
+    let mut fields = Fields(None);
+    let _ = T::deserialize(&mut fields);
+    fields.0.unwrap_or(&[])
+}
+
+
+/*----------------------------------------------------------------------------*/
+enum Segment
+{
+    Key(String),
+    Index(usize),
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub struct UnknownKey
+{
+    parents: Vec<Segment>,
+    key: String,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl UnknownKey
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(path: &Path<'_>) -> Option<Self>
+    {
+        let mut parents = Vec::new();
+        Self::collect(path, &mut parents);
+
+        match parents.pop()
+        {
+            Some(Segment::Key(key)) => Some(Self { parents, key }),
+            _ => None,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn collect(path: &Path<'_>,
+               segments: &mut Vec<Segment>)
+    {
+        use Path::*;
+        match path
+        {
+            Root => (),
+            Seq { parent, index } =>
+            {
+                Self::collect(parent, segments);
+                segments.push(Segment::Index(*index));
+            },
+            Map { parent, key } =>
+            {
+                Self::collect(parent, segments);
+                /* Skip the internal keys `toml::Spanned` is implemented with */
+                if !key.starts_with("$__serde_spanned")
+                {
+                    segments.push(Segment::Key(key.clone()));
+                }
+            },
+            Some { parent }
+            | NewtypeStruct { parent }
+            | NewtypeVariant { parent } => Self::collect(parent, segments),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn expected(&self) -> &'static [&'static str]
+    {
+        let keys = self.parents.iter()
+                               .filter_map(|segment|
+                                   match segment
+                                   {
+                                       Segment::Key(key) => Some(key.as_str()),
+                                       Segment::Index(_) => None,
+                                   })
+                               .collect::<Vec<&str>>();
+
+        match keys.as_slice()
+        {
+            [] => fields_of::<Gear>(),
+            ["meta"] => fields_of::<Meta>(),
+            ["meta", "weight"]
+            | ["meta", "price"] => fields_of::<Unit>(),
+            ["meta", "groups"] => fields_of::<GroupValues>(),
+            ["meta", "distances"] => fields_of::<DistancesValues>(),
+            ["meta", "temperatures"] => fields_of::<TemperaturesValues>(),
+            ["meta", "rules"] => fields_of::<Rules>(),
+            ["meta", "rules", "required"] => fields_of::<RequiredAttributes>(),
+            ["meta", "rules", "bounds"] => fields_of::<NumericBounds>(),
+            ["meta", "rules", _, "when"] => fields_of::<Condition>(),
+            ["meta", "lint"] => fields_of::<Check>(),
+            ["base"]
+            | ["consumables"] => fields_of::<Item>(),
+            _ => &[],
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn owner(&self) -> String
+    {
+        let mut owner = String::new();
+        for segment in self.parents.iter()
+        {
+            match segment
+            {
+                Segment::Key(key) if owner.is_empty() => owner.push_str(key),
+                Segment::Key(key) => owner.push_str(&format!(".{}", key)),
+                Segment::Index(index) => owner.push_str(&format!("[{}]", index)),
+            }
+        }
+
+        owner
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn span(&self, text: &str) -> Option<Range<usize>>
+    {
+        let document = ImDocument::parse(text).ok()?;
+        let mut item = document.as_item();
+        for segment in self.parents.iter()
+        {
+            item = match segment
+            {
+                Segment::Key(key) => item.get(key)?,
+                Segment::Index(index) => item.get(index)?,
+            };
+        }
+
+        let (key, _) = item.as_table_like()?.get_key_value(&self.key)?;
+        key.span()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn into_error(self, source: &Source) -> Error
+    {
+        let mut message =
+            if self.parents.is_empty()
+            {
+                format!("Unknown key `{}` at the top level", self.key)
+            }
+            else
+            {
+                format!("Unknown key `{}` in `{}`", self.key, self.owner())
+            };
+
+        let expected = self.expected();
+        if let Some(suggestion) = suggestion(&self.key, expected.iter().copied())
+        {
+            message.push_str(&format!(", did you mean `{}`?", suggestion));
+        }
+        else if !expected.is_empty()
+        {
+            message.push_str(&format!(", expected `{}`", expected.join("` or `")));
+        }
+
+        let error = match self.span(source.text())
+        {
+            Some(span) => Error::SourceError(message, source.location(span)),
+            None => message.into(),
+        };
+        error.hinted("Use `--lenient` to ignore unknown keys")
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use crate::input::{
+        fixtures,
+        Gear,
+        Source,
+    };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn unknown_keys_suggest_the_expected_ones()
+    {
+        let error = fixtures::gear("\n[[base]]\nkind = \"Tent\"\nwieght = 1\n")
+                        .err()
+                        .unwrap()
+                        .to_string();
+        assert!(error.starts_with(
+            "Unknown key `wieght` in `base[0]`, did you mean `weight`?\n"));
+        assert!(error.ends_with("Use `--lenient` to ignore unknown keys"));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn unknown_checks_are_unknown_keys()
+    {
+        let lint = "\n[meta.lint]\nmising-weight = \"off\"\n";
+        let error = fixtures::gear(lint).err().unwrap().to_string();
+        assert!(error.starts_with(
+            "Unknown key `mising-weight` in `meta.lint`, \
+             did you mean `missing-weight`?\n"));
+        assert!(error.ends_with("Use `--lenient` to ignore unknown keys"));
+
+        let text = format!("{}{}", fixtures::META, lint);
+        assert!(Gear::from_source(Source::new("gear.toml", text), false).is_ok());
+    }
+}
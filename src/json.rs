@@ -0,0 +1,160 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use serde::{
+    Serialize,
+    Serializer,
+    ser::SerializeMap,
+};
+
+use crate::{
+    input::{
+        Item,
+        Meta,
+        Number,
+        Formatters,
+        Shares,
+    },
+    totals::Totals,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Serialize)]
+struct Attribute<T>
+    where T: Serialize
+{
+    value: Option<T>,
+    formatted: Option<String>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+struct Row<'a>
+{
+    item: &'a Item,
+    columns: &'a [&'a str],
+    formatters: &'a Formatters<'a>,
+    shares: &'a Shares,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Serialize for Row<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut map = serializer.serialize_map(Some(self.columns.len() + 1))?;
+        map.serialize_entry("section", &self.item.section().to_string())?;
+        for &column in self.columns
+        {
+            let attribute =
+                Attribute
+                {
+                    value: self.item.value(column, self.shares),
+                    formatted: self.item.formatted(column,
+                                                   self.formatters,
+                                                   self.shares),
+                };
+            map.serialize_entry(column, &attribute)?;
+        }
+
+        map.end()
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+struct Summary<'a>
+{
+    totals: &'a Totals,
+    formatters: &'a Formatters<'a>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Serialize for Summary<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut map = serializer.serialize_map(Some(Item::NUMERIC_FIELDS.len()))?;
+        for &attribute in Item::NUMERIC_FIELDS.iter()
+        {
+            let attribute_total: Attribute<Number> =
+                Attribute
+                {
+                    value: Some(self.totals.number(attribute)),
+                    formatted: Some(self.totals.formatted(attribute,
+                                                          self.formatters)),
+                };
+            map.serialize_entry(attribute, &attribute_total)?;
+        }
+
+        map.end()
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Serialize)]
+struct Document<'a>
+{
+    columns: &'a [&'a str],
+    items: Vec<Row<'a>>,
+    count: usize,
+    totals: Summary<'a>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Json(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Json
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(columns: &[&str],
+               items: &[&Item],
+               meta: &Meta,
+               shares: &Shares) -> Self
+    {
+        let formatters = meta.formatters();
+        let totals = Totals::new(items);
+
+        let document =
+            Document
+            {
+                columns,
+                items: items.iter()
+                            .map(|&item| Row { item,
+                                               columns,
+                                               formatters: &formatters,
+                                               shares })
+                            .collect(),
+                count: items.len(),
+                totals: Summary { totals: &totals, formatters: &formatters },
+            };
+
+        /* Serializing the document cannot fail, as all the keys are strings */
+        Self(serde_json::to_string_pretty(&document).unwrap())
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Json
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
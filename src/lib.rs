@@ -1,4 +1,5 @@
 mod error;
+mod location;
 mod arguments;
 mod input;
 mod filter;
@@ -6,10 +7,29 @@ mod filters;
 mod table;
 mod column;
 mod sum;
+mod totals;
+mod groups;
+mod json;
+mod delimited;
+mod markdown;
+mod html;
+mod chart;
+mod template;
+mod checklist;
+mod packing;
+mod document;
+mod diff;
+mod lighterpack;
 mod validate;
 mod validators;
+mod lint;
+mod lints;
+mod heavy;
+mod colors;
+mod suggest;
+mod terminal;
 
-use std::ops::Deref;
+use clap::ArgMatches;
 
 pub use error::{
     Result,
@@ -19,60 +39,437 @@ use arguments::arguments;
 use input::{
     Gear,
     Item,
+    Section,
+    Shares,
 };
-use table::Table;
+use table::{
+    Table,
+    Style,
+};
+use column::{
+    Overflow,
+    Emphasis,
+};
+use colors::Colors;
 use sum::Sum;
+use json::Json;
+use delimited::Delimited;
+use markdown::Markdown;
+use html::Html;
+use chart::Chart;
+use template::Template;
+use checklist::Checklist;
+use lint::Lint;
+use lints::Severity;
+use packing::Packing;
+use document::Document;
+use groups::grouped;
 
 
 /*----------------------------------------------------------------------------*/
 pub fn main() -> Result<()>
 {
     let arguments = arguments();
-    let gear = Gear::from_toml(arguments.value_of("path").unwrap())?;
-    let results =
+    if let ("import", Some(import)) = arguments.subcommand()
+    {
+        print!("{}", lighterpack::Import::new(import.value_of("csv").unwrap())?);
+        return Ok(());
+    }
+
+    let path = arguments.value_of("path").unwrap();
+    let gear = Gear::from_toml(path, !arguments.is_present("lenient"))?;
+
+    match arguments.subcommand()
+    {
+        ("lint", Some(_)) => lint(&gear),
+        ("pack", Some(pack_arguments)) => pack(pack_arguments, &gear, path),
+        (command @ ("add" | "set" | "remove"), Some(edit_arguments)) =>
+            edit(command,
+                 edit_arguments,
+                 &gear,
+                 path,
+                 !arguments.is_present("lenient")),
+        ("rename", Some(rename_arguments)) =>
+            rename(rename_arguments, &gear, path, !arguments.is_present("lenient")),
+        ("fmt", Some(fmt_arguments)) =>
+            fmt(fmt_arguments, path),
+        _ => query(&arguments, &gear),
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn lint(gear: &Gear) -> Result<()>
+{
+    let lint = Lint::new(gear);
+    print!("{}", lint);
+
+    let errors = lint.count(Severity::Error);
+    let warnings = lint.count(Severity::Warning);
+    let summary = format!("{} error{} and {} warning{} found",
+                          errors,
+                          if errors == 1 { "" } else { "s" },
+                          warnings,
+                          if warnings == 1 { "" } else { "s" });
+
+    if errors > 0
+    {
+        Err(summary.into())
+    }
+    else
+    {
+        println!("{}", summary);
+        Ok(())
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn pack(arguments: &ArgMatches<'_>,
+        gear: &Gear,
+        file_name: &str) -> Result<()>
+{
+    let trip = arguments.value_of("trip").unwrap();
+    let mut packing = Packing::load(file_name)?;
+
+    match arguments.subcommand()
+    {
+        (command @ ("check" | "uncheck"), Some(command_arguments)) =>
         {
-            let mut results =
-                gear.filter_and_validate(arguments.is_present("all"),
-                                         arguments.is_present("base"),
-                                         arguments.is_present("consumables"),
-                                         arguments.values_of("groups"),
-                                         arguments.values_of("distances"),
-                                         arguments.values_of("temperatures"))?;
-            let comparer = Item::comparer_by(arguments.value_of("sort").unwrap());
-            results.sort_unstable_by(|&left, &right| comparer(left, right));
-            results
+            /* Validate all the ids before changing anything */
+            let ids = command_arguments.values_of("ids").unwrap()
+                                                        .collect::<Vec<&str>>();
+            for id in ids.iter()
+            {
+                gear.validate_id(id)?;
+            }
+
+            for id in ids
+            {
+                if command == "check"
+                {
+                    packing.check(trip, id);
+                }
+                else
+                {
+                    packing.uncheck(trip, id);
+                }
+            }
+            packing.save()
+        },
+        ("reset", Some(_)) =>
+        {
+            packing.reset(trip);
+            packing.save()
+        },
+        ("status", Some(_)) =>
+        {
+            let items = gear.items().collect::<Vec<&Item>>();
+            let missing = packing.missing(trip, &items);
+
+            println!("{} of {} items packed for trip `{}`",
+                     items.len() - missing.len(),
+                     items.len(),
+                     trip);
+
+            for (group, items) in grouped(&missing)
+            {
+                println!("\n{}", group.unwrap_or(groups::UNGROUPED));
+                for item in items
+                {
+                    match item.name()
+                    {
+                        Some(name) => println!("  {} ({})", item.kind(), name),
+                        None => println!("  {}", item.kind()),
+                    }
+                }
+            }
+
+            Ok(())
+        },
+        _ => unreachable!(),
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn edit(command: &str,
+        arguments: &ArgMatches<'_>,
+        gear: &Gear,
+        file_name: &str,
+        is_strict: bool) -> Result<()>
+{
+    let mut document = Document::load(file_name)?;
+    let assignments = arguments.values_of("assignments")
+                               .into_iter()
+                               .flatten()
+                               .map(Document::assignment)
+                               .collect::<Result<Vec<_>>>()?;
+
+    match command
+    {
+        "add" =>
+        {
+            if !assignments.iter().any(|(field, value)| *field == "kind"
+                                                        && value.is_some())
+            {
+                return Err("The `kind` of the new item is missing".to_string()
+                                                                   .into());
+            }
+
+            let section =
+                if arguments.is_present("consumable") { Section::Consumables }
+                else { Section::Base };
+            document.add(section, &assignments)?;
+        },
+        "set" =>
+        {
+            let id = arguments.value_of("id").unwrap();
+            gear.validate_id(id)?;
+            document.set(id, assignments)?;
+        },
+        "remove" =>
+        {
+            let id = arguments.value_of("id").unwrap();
+            gear.validate_id(id)?;
+            document.remove(id)?;
+        },
+        _ => unreachable!(),
+    }
+
+    document.validate(is_strict)?;
+    document.save()
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn rename(arguments: &ArgMatches<'_>,
+          gear: &Gear,
+          file_name: &str,
+          is_strict: bool) -> Result<()>
+{
+    let attribute = arguments.value_of("attribute").unwrap();
+    let from = arguments.value_of("from").unwrap();
+    let to = arguments.value_of("to").unwrap();
+
+    let mut document = Document::load(file_name)?;
+    if document.rename(attribute, from, to) == 0
+    {
+        let value = Some(std::iter::once(from));
+        let none = || None;
+        let unknown = match attribute
+        {
+            "group" => gear.unknown_values(value, none(), none()),
+            "distance" => gear.unknown_values(none(), value, none()),
+            "temperature" => gear.unknown_values(none(), none(), value),
+            _ => unreachable!(),
         };
 
+        return Err(unknown.into_iter()
+                          .next()
+                          .unwrap_or_else(|| format!("The {} `{}` is not used",
+                                                     attribute,
+                                                     from))
+                          .into());
+    }
+
+    document.validate(is_strict)?;
+    if arguments.is_present("dry_run")
+    {
+        print!("{}", document.diff());
+        Ok(())
+    }
+    else
+    {
+        document.save()
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn fmt(arguments: &ArgMatches<'_>,
+       file_name: &str) -> Result<()>
+{
+    let mut document = Document::load(file_name)?;
+    document.format(arguments.value_of("sort").unwrap());
+
+    if !arguments.is_present("check")
+    {
+        return document.save();
+    }
+
+    let diff = document.diff();
+    if diff.is_empty()
+    {
+        Ok(())
+    }
+    else
+    {
+        print!("{}", diff);
+        Err(format!("`{}` is not formatted", file_name).into())
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn query(arguments: &ArgMatches<'_>,
+         gear: &Gear) -> Result<()>
+{
+    if !arguments.is_present("allow_unknown")
+    {
+        gear.validate_filters(arguments.values_of("groups"),
+                              arguments.values_of("distances"),
+                              arguments.values_of("temperatures"))?;
+    }
+
+    let mut results =
+        gear.filter_and_validate(arguments.is_present("all"),
+                                 arguments.is_present("base"),
+                                 arguments.is_present("consumables"),
+                                 arguments.values_of("groups"),
+                                 arguments.values_of("distances"),
+                                 arguments.values_of("temperatures"))?;
+    let shares = Shares::new(&results);
+
+    match arguments.value_of("sort").unwrap()
+    {
+        column @ ("weight%" | "price%" | "group_weight%") =>
+            results.sort_unstable_by(
+                |&left, &right| shares.compare(left, right, column)),
+        field =>
+        {
+            let comparer = Item::comparer_by(field);
+            results.sort_unstable_by(|&left, &right| comparer(left, right));
+        },
+    }
+
+    match arguments.value_of("order").unwrap()
+    {
+        "ascending" => (),
+        "descending" => results.reverse(),
+        _ => unreachable!(),
+    }
+
+    let headers = gear.columns(arguments.values_of("columns"))?;
+
+    if let Some(template) = arguments.value_of("template")
+    {
+        print!("{}", Template::new(template,
+                                   &results,
+                                   gear.meta().formatters(),
+                                   &shares)?);
+        return Ok(());
+    }
+    else if let Some(file_name) = arguments.value_of("template_file")
+    {
+        print!("{}", Template::from_file(file_name,
+                                         &results,
+                                         gear.meta().formatters(),
+                                         &shares)?);
+        return Ok(());
+    }
+
+    match arguments.value_of("format").unwrap()
+    {
+        "table" => print_table(arguments, gear, headers, &results, &shares),
+        "json" =>
+            println!("{}", Json::new(&headers, &results, gear.meta(), &shares)),
+        format @ ("csv" | "tsv") =>
+        {
+            let delimiter = if format == "csv" { b',' } else { b'\t' };
+            let delimited =
+                Delimited::new(delimiter,
+                               &headers,
+                               &results,
+                               gear.meta().formatters(),
+                               &shares,
+                               arguments.value_of("list_separator").unwrap(),
+                               arguments.is_present("raw"))?;
+            print!("{}", delimited);
+        },
+        "markdown" =>
+        {
+            let markdown = Markdown::new(&headers,
+                                         &results,
+                                         gear.meta().formatters(),
+                                         &shares,
+                                         arguments.value_of("sum"));
+            print!("{}", markdown);
+        },
+        "html" =>
+            print!("{}", Html::new(&headers,
+                                   &results,
+                                   gear.meta().formatters(),
+                                   &shares)),
+        "lighterpack" =>
+            print!("{}", lighterpack::Export::new(&results, gear.meta())?),
+        format @ ("checklist" | "markdown-checklist") =>
+            print!("{}", Checklist::new(&results, format == "markdown-checklist")),
+        "chart" =>
+            print!("{}", Chart::new(&results,
+                                    arguments.value_of("chart_by").unwrap(),
+                                    arguments.value_of("chart_of").unwrap(),
+                                    gear.meta().formatters(),
+                                    max_width(arguments).unwrap_or(80))),
+        _ => unreachable!(),
+    }
+
+    Ok(())
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn print_table(arguments: &ArgMatches<'_>,
+               gear: &Gear,
+               headers: Vec<&str>,
+               results: &[&Item],
+               shares: &Shares)
+{
     if results.is_empty()
     {
         println!("No items found");
+
+        for unknown in gear.unknown_values(arguments.values_of("groups"),
+                                           arguments.values_of("distances"),
+                                           arguments.values_of("temperatures"))
+        {
+            println!("{}", unknown);
+        }
     }
     else
     {
-        let headers =
+        let max_width = max_width(arguments);
+        let overflow =
+            match arguments.value_of("overflow").unwrap()
             {
-                let mut headers = Vec::with_capacity(Item::FIELDS.len());
-                match arguments.values_of("columns")
-                {
-                    Some(columns) => headers.extend(columns),
-                    None => headers.extend_from_slice(&Item::FIELDS),
-                }
-
-                headers
+                "wrap" => Overflow::Wrap,
+                "truncate" => Overflow::Truncate,
+                _ => unreachable!(),
             };
-
-        let table =
-            match arguments.value_of("order").unwrap()
+        let is_colored =
+            match arguments.value_of("color").unwrap()
             {
-                "ascending" => Table::new(headers,
-                                          results.iter().map(Deref::deref),
-                                          gear.meta().formatters()),
-                "descending" => Table::new(headers,
-                                           results.iter().rev().map(Deref::deref),
-                                           gear.meta().formatters()),
+                "auto" => terminal::is_colored(),
+                "always" => true,
+                "never" => false,
                 _ => unreachable!(),
             };
-
+        let colors =
+            is_colored.then(
+                || Colors::new(gear,
+                               arguments.value_of("heavy")
+                                        .map(|weight| weight.parse().unwrap())));
+        /* The table already shows the totals of its numeric columns */
+        let sum = arguments.value_of("sum")
+                           .filter(|column| !headers.contains(column));
+        let table = Table::new(headers,
+                               results,
+                               gear.meta().formatters(),
+                               shares,
+                               max_width,
+                               overflow,
+                               Style::by_name(arguments.value_of("style").unwrap()),
+                               colors.as_ref());
         println!("{}", table);
 
         match results.len()
@@ -81,11 +478,26 @@ pub fn main() -> Result<()>
             len => println!("{} items found", len),
         }
 
-        if let Some(column) = arguments.value_of("sum")
+        if let Some(column) = sum
         {
-            println!("{}", Sum::new(column, gear.meta(), &results));
+            let sum = Sum::new(column, gear.meta(), results);
+            if is_colored
+            {
+                println!("{}", Emphasis::Bold.paint(sum));
+            }
+            else
+            {
+                println!("{}", sum);
+            }
         }
     }
+}
 
-    Ok(())
+
+/*----------------------------------------------------------------------------*/
+fn max_width(arguments: &ArgMatches<'_>) -> Option<usize>
+{
+    arguments.value_of("width")
+             .map(|width| width.parse().unwrap())
+             .or_else(terminal::width)
 }
@@ -0,0 +1,79 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use csv::Writer;
+
+use crate::{
+    delimited::into_string,
+    input::{
+        Item,
+        Meta,
+        Section,
+    },
+    lighterpack::{
+        HEADERS,
+        WeightUnit,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Export(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Export
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(items: &[&Item],
+               meta: &Meta) -> crate::Result<Self>
+    {
+        let symbol = meta.weight().symbol();
+        let unit =
+            WeightUnit::from_symbol(symbol).ok_or_else(
+                || format!("Weight unit `{}` is not supported by LighterPack, \
+                            expected one of: {}",
+                           symbol,
+                           WeightUnit::SYMBOLS.join(", ")))?;
+
+        let mut writer = Writer::from_writer(Vec::new());
+        writer.write_record(HEADERS.iter())?;
+        for item in items
+        {
+            let worn = if item.is_worn() { "Worn" } else { "" };
+            let consumable =
+                match item.section()
+                {
+                    Section::Base => "",
+                    Section::Consumables => "Consumable",
+                };
+
+            writer.write_record([item.kind(),
+                                 item.group().unwrap_or_default(),
+                                 item.name().unwrap_or_default(),
+                                 &item.quantity().to_string(),
+                                 &item.weight().to_string(),
+                                 unit.symbol(),
+                                 item.url().unwrap_or_default(),
+                                 &item.price().to_string(),
+                                 worn,
+                                 consumable].iter())?;
+        }
+
+        Ok(Self(into_string(writer)?))
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Export
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
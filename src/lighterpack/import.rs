@@ -0,0 +1,257 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+use csv::ReaderBuilder;
+use serde::Serialize;
+
+use crate::{
+    input::{
+        Gear,
+        Source,
+    },
+    lighterpack::{
+        HEADERS,
+        WeightUnit,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Serialize)]
+struct Unit
+{
+    symbol: &'static str,
+    position: &'static str,
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Serialize)]
+struct Values
+{
+    values: BTreeSet<String>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Serialize)]
+struct Meta
+{
+    weight: Unit,
+    price: Unit,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    groups: Option<Values>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Serialize)]
+struct Item
+{
+    kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantity: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    worn: Option<bool>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Serialize)]
+struct Document
+{
+    meta: Meta,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    base: Vec<Item>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    consumables: Vec<Item>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Import(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Import
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(file_name: &str) -> crate::Result<Self>
+    {
+        let mut reader = ReaderBuilder::new().flexible(true)
+                                             .from_path(file_name)?;
+
+        /* Columns are looked up by their header, so their order is irrelevant */
+        let indices =
+            {
+                let headers = reader.headers()?;
+                let mut indices = [0; HEADERS.len()];
+                for (index, expected) in indices.iter_mut().zip(HEADERS.iter())
+                {
+                    *index =
+                        headers.iter()
+                               .position(|header|
+                                   header.trim().eq_ignore_ascii_case(expected))
+                               .ok_or_else(
+                                   || format!("{}: missing column `{}`",
+                                              file_name, expected))?;
+                }
+
+                indices
+            };
+
+        let mut groups = BTreeSet::new();
+        let mut document =
+            Document
+            {
+                meta: Meta
+                {
+                    weight: Unit { symbol: "g", position: "SUFFIX" },
+                    price: Unit { symbol: "$", position: "PREFIX" },
+                    groups: None,
+                },
+                base: Vec::new(),
+                consumables: Vec::new(),
+            };
+
+        for record in reader.records()
+        {
+            let record = record?;
+            let line = record.position().map_or(0, |position| position.line());
+            let field = |column: &str|
+            {
+                let index = HEADERS.iter()
+                                   .position(|header| *header == column)
+                                   .unwrap();
+                record.get(indices[index]).map(str::trim).unwrap_or_default()
+            };
+            let error = |message: String| format!("{}:{}: {}", file_name, line, message);
+
+            let (item, is_consumable) = Self::item(&field).map_err(error)?;
+            if let Some(group) = item.group.as_ref()
+            {
+                groups.insert(group.clone());
+            }
+
+            if is_consumable
+            {
+                document.consumables.push(item);
+            }
+            else
+            {
+                document.base.push(item);
+            }
+        }
+
+        if !groups.is_empty()
+        {
+            document.meta.groups = Some(Values { values: groups });
+        }
+
+        /* The document only consists of strings, numbers, booleans, tables and
+           arrays of tables, all of which are representable in TOML */
+        let text = toml::to_string(&document).unwrap();
+        Gear::from_source(Source::new(&format!("{} (converted)", file_name),
+                                      text.clone()),
+                          true)?.validate_items()?;
+
+        Ok(Self(text))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn item<'a, F>(field: &F) -> Result<(Item, bool), String>
+        where F: Fn(&str) -> &'a str
+    {
+        let text = |column| Some(field(column)).filter(|value| !value.is_empty())
+                                                .map(String::from);
+        let flag = |column| !matches!(field(column).to_lowercase().as_str(),
+                                      "" | "0" | "false" | "no");
+
+        let kind = text("Item Name").ok_or("missing item name")?;
+
+        let weight =
+            match field("weight")
+            {
+                "" => None,
+                weight =>
+                {
+                    let weight = weight.parse::<f64>().map_err(
+                        |_| format!("invalid weight `{}`", weight))?;
+                    let unit =
+                        match field("unit")
+                        {
+                            "" => WeightUnit::Gram,
+                            unit => WeightUnit::from_symbol(unit).ok_or_else(
+                                || format!("unknown weight unit `{}`", unit))?,
+                        };
+                    Some(unit.in_grams(weight).round() as i32)
+                },
+            };
+
+        let price =
+            match field("price")
+            {
+                "" => None,
+                price => Some(price.parse::<f64>().map_err(
+                    |_| format!("invalid price `{}`", price))?),
+            };
+
+        let quantity =
+            match field("qty")
+            {
+                "" => None,
+                /* Gear lists have no items of no pieces */
+                text =>
+                {
+                    let quantity = text.parse::<u32>().ok()
+                                                      .filter(|quantity| *quantity > 0);
+                    Some(quantity.ok_or_else(
+                        || format!("invalid quantity `{}`, expected at least `1`",
+                                   text))?)
+                },
+            };
+
+        /* LighterPack writes zeros and a quantity of one for unset values */
+        let item =
+            Item
+            {
+                kind,
+                name: text("desc"),
+                group: text("Category"),
+                weight: weight.filter(|weight| *weight != 0),
+                price: price.filter(|price| *price != 0.0),
+                quantity: quantity.filter(|quantity| *quantity != 1),
+                url: text("url"),
+                worn: Some(true).filter(|_| flag("worn")),
+            };
+
+        Ok((item, flag("consumable")))
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Import
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
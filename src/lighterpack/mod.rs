@@ -0,0 +1,144 @@
+mod weight_unit;
+mod export;
+mod import;
+
+use weight_unit::WeightUnit;
+pub use export::Export;
+pub use import::Import;
+
+
+/*----------------------------------------------------------------------------*/
+const HEADERS: [&str; 10] = ["Item Name",
+                             "Category",
+                             "desc",
+                             "qty",
+                             "weight",
+                             "unit",
+                             "url",
+                             "price",
+                             "worn",
+                             "consumable"];
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use std::{
+        env,
+        fs,
+        process,
+    };
+
+    use super::*;
+    use crate::input::{
+        fixtures,
+        Gear,
+        Item,
+        Section,
+        Source,
+    };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const ITEMS: &str = "
+[[base]]
+kind = \"Pot, \\\"Titanium\\\"\"
+name = \"Toaks 750\"
+group = \"Kitchen\"
+weight = 103
+price = 34.5
+url = \"https://example.com/pot\"
+
+[[base]]
+kind = \"Jacket\"
+group = \"Clothing\"
+weight = 350
+worn = true
+
+[[consumables]]
+kind = \"Gas\"
+group = \"Kitchen\"
+weight = 230
+quantity = 2
+";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    type Fields<'a> = (&'a str,
+                       Option<&'a str>,
+                       Option<&'a str>,
+                       i32,
+                       f32,
+                       u32,
+                       Option<&'a str>,
+                       bool,
+                       bool);
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fields(gear: &Gear) -> Vec<Fields<'_>>
+    {
+        gear.items()
+            .map(|item| (item.kind(),
+                         item.name(),
+                         item.group(),
+                         item.weight(),
+                         item.price(),
+                         item.quantity(),
+                         item.url(),
+                         item.is_worn(),
+                         item.section() == Section::Consumables))
+            .collect()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn import(csv: &str,
+              name: &str) -> crate::Result<Import>
+    {
+        let path = env::temp_dir().join(format!("gear-lighterpack-{}-{}.csv",
+                                                name,
+                                                process::id()));
+        fs::write(&path, csv).unwrap();
+        let import = Import::new(path.to_str().unwrap());
+        fs::remove_file(&path).unwrap();
+
+        import
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn exported_items_are_imported_unchanged()
+    {
+        let gear = fixtures::gear(ITEMS).unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+        let export = Export::new(&items, gear.meta()).unwrap();
+
+        let imported = import(&export.to_string(), "round-trip").unwrap();
+        let imported = Gear::from_source(Source::new("imported.toml",
+                                                     imported.to_string()),
+                                         true).unwrap();
+        assert_eq!(fields(&imported), fields(&gear));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn export_needs_a_weight_unit_of_lighterpack()
+    {
+        let text = format!("{}{}", fixtures::META, ITEMS).replacen("\"g\"",
+                                                                  "\"st\"",
+                                                                  1);
+        let gear = Gear::from_source(Source::new("gear.toml", text), true).unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+
+        assert!(Export::new(&items, gear.meta()).is_err());
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn items_of_no_pieces_are_not_imported()
+    {
+        let csv = format!("{}\nStove,Kitchen,,1,73,g,,,,\nGas,Kitchen,,0,230,g,,,,\n",
+                          HEADERS.join(","));
+        let error = import(&csv, "no-pieces").err().unwrap().to_string();
+
+        assert!(error.ends_with(":3: invalid quantity `0`, expected at least `1`"));
+    }
+}
@@ -0,0 +1,57 @@
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy)]
+pub enum WeightUnit
+{
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl WeightUnit
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub const SYMBOLS: [&'static str; 4] = ["g", "kg", "oz", "lb"];
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn from_symbol(symbol: &str) -> Option<Self>
+    {
+        use WeightUnit::*;
+        match symbol.trim().to_lowercase().as_str()
+        {
+            "g" | "gram" | "grams" => Some(Gram),
+            "kg" | "kilogram" | "kilograms" => Some(Kilogram),
+            "oz" | "ounce" | "ounces" => Some(Ounce),
+            "lb" | "lbs" | "pound" | "pounds" => Some(Pound),
+            _ => None,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn symbol(self) -> &'static str
+    {
+        use WeightUnit::*;
+        match self
+        {
+            Gram => Self::SYMBOLS[0],
+            Kilogram => Self::SYMBOLS[1],
+            Ounce => Self::SYMBOLS[2],
+            Pound => Self::SYMBOLS[3],
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn in_grams(self, value: f64) -> f64
+    {
+        use WeightUnit::*;
+        match self
+        {
+            Gram => value,
+            Kilogram => value*1000.0,
+            Ounce => value*28.349_523_125,
+            Pound => value*453.592_37,
+        }
+    }
+}
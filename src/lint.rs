@@ -0,0 +1,224 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+use crate::{
+    input::{
+        Gear,
+        Item,
+        Meta,
+    },
+    location::Location,
+    heavy::HeavyItems,
+    lints::{
+        Check,
+        Severity,
+        Finding,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Lint
+{
+    findings: Vec<Finding>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Lint
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(gear: &Gear) -> Self
+    {
+        let mut lint = Self { findings: Vec::new() };
+
+        lint.validate(gear);
+        lint.check_missing(gear);
+        lint.check_heavy_items(gear);
+        lint.check_unused_values(gear);
+
+        /* Report the findings in the order they appear in the file */
+        lint.findings.sort_by_key(|finding| finding.line().unwrap_or(usize::MAX));
+        lint
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn count(&self, severity: Severity) -> usize
+    {
+        self.findings.iter()
+                     .filter(|finding| finding.severity() == severity)
+                     .count()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn push(&mut self, meta: &Meta,
+                       check: Check,
+                       message: String,
+                       location: Option<Location>)
+    {
+        let severity = meta.severity(check);
+        if severity != Severity::Off
+        {
+            self.findings.push(Finding::new(check, severity, message, location));
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn validate(&mut self, gear: &Gear)
+    {
+        let validators = gear.validators();
+        for item in gear.spanned_items()
+        {
+            for validator in validators.iter()
+            {
+                if let Err(error) = validator.validate(item.get_ref())
+                {
+                    let location =
+                        gear.source().attribute_location(item.span(),
+                                                         &validator.attribute());
+                    self.push(gear.meta(),
+                              validator.check(),
+                              error.to_string(),
+                              Some(location));
+                }
+            }
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn check_missing(&mut self, gear: &Gear)
+    {
+        let checks = [(Check::MissingWeight, "weight"),
+                      (Check::MissingPrice, "price"),
+                      (Check::MissingGroup, "group")];
+
+        for item in gear.spanned_items()
+        {
+            for &(check, attribute) in checks.iter()
+            {
+                /* Missing groups are already reported by the validator, if
+                   there are declared groups in the meta section */
+                if check == Check::MissingGroup && gear.meta().groups().is_some()
+                {
+                    continue;
+                }
+
+                if !item.get_ref().is_present(attribute)
+                {
+                    let location = gear.source().location(item.span());
+                    self.push(gear.meta(),
+                              check,
+                              format!("`{}` has no `{}`",
+                                      item.get_ref().id(),
+                                      attribute),
+                              Some(location));
+                }
+            }
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn check_heavy_items(&mut self, gear: &Gear)
+    {
+        let heavy_items = HeavyItems::new(gear);
+        for item in gear.spanned_items()
+        {
+            let inner = item.get_ref();
+            if heavy_items.is_heavy(inner)
+            {
+                let median = heavy_items.median(inner).unwrap();
+                let unit = gear.meta().weight();
+                let mut weight = String::new();
+                unit.format_to_string(inner.weight(), &mut weight);
+                let mut median_weight = String::new();
+                unit.format_to_string(median, &mut median_weight);
+
+                let location = gear.source().attribute_location(item.span(),
+                                                                "weight");
+                self.push(gear.meta(),
+                          Check::HeavyItem,
+                          format!("`{}` weighs {}, which is more than {} times \
+                                   the median weight ({}) of group `{}`",
+                                  inner.id(),
+                                  weight,
+                                  HeavyItems::FACTOR,
+                                  median_weight,
+                                  inner.group().unwrap()),
+                          Some(location));
+            }
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn check_unused<'a>(&mut self, meta: &Meta,
+                                   attribute: &str,
+                                   declared: impl Iterator<Item = &'a str>,
+                                   used: impl Iterator<Item = &'a str>)
+    {
+        let used = used.collect::<BTreeSet<&str>>();
+        let unused = declared.filter(|value| !used.contains(value))
+                             .collect::<BTreeSet<&str>>();
+        for value in unused
+        {
+            self.push(meta,
+                      Check::UnusedValue,
+                      format!("`{}` is declared in `meta.{}`, but it is not \
+                               used by any item",
+                              value,
+                              attribute),
+                      None);
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn check_unused_values(&mut self, gear: &Gear)
+    {
+        let meta = gear.meta();
+
+        if let Some(groups) = meta.groups()
+        {
+            self.check_unused(meta,
+                              "groups",
+                              groups.values(),
+                              gear.items().filter_map(Item::group));
+        }
+
+        if let Some(distances) = meta.distances()
+        {
+            self.check_unused(meta,
+                              "distances",
+                              distances.values(),
+                              gear.items().flat_map(Item::distances));
+        }
+
+        if let Some(temperatures) = meta.temperatures()
+        {
+            self.check_unused(meta,
+                              "temperatures",
+                              temperatures.values(),
+                              gear.items().flat_map(Item::temperatures));
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Lint
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        for finding in self.findings.iter()
+        {
+            writeln!(f, "{}\n", finding)?;
+        }
+
+        Ok(())
+    }
+}
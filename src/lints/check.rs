@@ -0,0 +1,144 @@
+use std::{
+    collections::HashMap,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+use serde::{
+    de::{
+        value,
+        IgnoredAny,
+        IntoDeserializer,
+        MapAccess,
+        Visitor,
+    },
+    Deserialize,
+    Deserializer,
+};
+
+use crate::lints::Severity;
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Check
+{
+    GroupValues,
+    DistancesValues,
+    TemperaturesValues,
+    RequiredAttributes,
+    NumericBounds,
+    MissingWeight,
+    MissingPrice,
+    MissingGroup,
+    HeavyItem,
+    UnusedValue,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Check
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn default_severity(self) -> Severity
+    {
+        use Check::*;
+        match self
+        {
+            GroupValues
+            | DistancesValues
+            | TemperaturesValues
+            | RequiredAttributes
+            | NumericBounds => Severity::Error,
+            MissingWeight
+            | MissingPrice
+            | MissingGroup
+            | HeavyItem
+            | UnusedValue => Severity::Warning,
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Check
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        use Check::*;
+        let id =
+            match self
+            {
+                GroupValues => "group-values",
+                DistancesValues => "distances-values",
+                TemperaturesValues => "temperatures-values",
+                RequiredAttributes => "required-attributes",
+                NumericBounds => "numeric-bounds",
+                MissingWeight => "missing-weight",
+                MissingPrice => "missing-price",
+                MissingGroup => "missing-group",
+                HeavyItem => "heavy-item",
+                UnusedValue => "unused-value",
+            };
+
+        write!(f, "{}", id)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* The values of unknown checks are skipped like the ones of unknown keys, so
+   they are reported with a suggestion, unless `--lenient` ignores them */
+struct Severities;
+
+
+/*----------------------------------------------------------------------------*/
+impl<'de> Visitor<'de> for Severities
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    type Value = HashMap<Check, Severity>;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result
+    {
+        formatter.write_str("a table of checks and their severities")
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where A: MapAccess<'de>
+    {
+        let mut severities = HashMap::new();
+        while let Some(key) = map.next_key::<String>()?
+        {
+            let check = key.as_str().into_deserializer();
+            match Check::deserialize::<value::StrDeserializer<'_, value::Error>>(check)
+            {
+                Ok(check) =>
+                {
+                    severities.insert(check, map.next_value()?);
+                },
+                Err(_) =>
+                {
+                    map.next_value::<IgnoredAny>()?;
+                },
+            }
+        }
+
+        Ok(severities)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub fn deserialize_severities<'de, D>(deserializer: D)
+    -> Result<HashMap<Check, Severity>, D::Error>
+    where D: Deserializer<'de>
+{
+    deserializer.deserialize_map(Severities)
+}
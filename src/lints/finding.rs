@@ -0,0 +1,66 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use crate::{
+    location::Location,
+    lints::{
+        Check,
+        Severity,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Finding
+{
+    check: Check,
+    severity: Severity,
+    message: String,
+    location: Option<Location>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Finding
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(check: Check,
+               severity: Severity,
+               message: String,
+               location: Option<Location>) -> Self
+    {
+        Self { check, severity, message, location }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn severity(&self) -> Severity
+    {
+        self.severity
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn line(&self) -> Option<usize>
+    {
+        self.location.as_ref().map(Location::line)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Finding
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}[{}]: {}", self.severity, self.check, self.message)?;
+        if let Some(location) = self.location.as_ref()
+        {
+            write!(f, "\n{}", location)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+mod check;
+mod severity;
+mod finding;
+
+pub use check::{
+    Check,
+    deserialize_severities,
+};
+pub use severity::Severity;
+pub use finding::Finding;
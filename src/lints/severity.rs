@@ -0,0 +1,35 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use serde::Deserialize;
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity
+{
+    Off,
+    Warning,
+    Error,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Severity
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        use Severity::*;
+        match self
+        {
+            Off => write!(f, "off"),
+            Warning => write!(f, "warning"),
+            Error => write!(f, "error"),
+        }
+    }
+}
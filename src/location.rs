@@ -0,0 +1,79 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Debug)]
+pub struct Location
+{
+    path: String,
+    line: usize,
+    column: usize,
+    snippet: String,
+    underline: String,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Location
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(path: &str,
+               line: usize,
+               column: usize,
+               snippet: &str,
+               width: usize) -> Self
+    {
+        /* Mirror the tabs of the snippet, so the caret ends up right below the
+           offending part regardless of the tab width of the terminal */
+        let mut underline = snippet.chars()
+                                   .take(column - 1)
+                                   .map(|c| if c == '\t' { '\t' } else { ' ' })
+                                   .collect::<String>();
+        for _ in 0..width.max(1)
+        {
+            underline.push('^');
+        }
+
+        Self
+        {
+            path: path.into(),
+            line,
+            column,
+            snippet: snippet.into(),
+            underline,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn line(&self) -> usize
+    {
+        self.line
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn column(&self) -> usize
+    {
+        self.column
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Location
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        let gutter = self.line.to_string().len();
+
+        writeln!(f, "{:gutter$}--> {}:{}:{}",
+                 "", self.path, self.line, self.column, gutter = gutter)?;
+        writeln!(f, "{:gutter$} |", "", gutter = gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{:gutter$} | {}", "", self.underline, gutter = gutter)
+    }
+}
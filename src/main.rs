@@ -1,11 +1,9 @@
-use gear;
-
-
 /*----------------------------------------------------------------------------*/
 fn main()
 {
     if let Err(error) = gear::main()
     {
         eprintln!("Error: {}", error);
+        std::process::exit(1);
     }
 }
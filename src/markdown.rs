@@ -0,0 +1,178 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use crate::{
+    input::{
+        Item,
+        Formatters,
+        Shares,
+    },
+    column::{
+        Column,
+        Alignment,
+    },
+    totals::Totals,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Markdown
+{
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<Column>>,
+    footer: Option<String>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Markdown
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const MIN_WIDTH: usize = 3;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(headers: &[&str],
+               items: &[&Item],
+               formatters: Formatters<'_>,
+               shares: &Shares,
+               sum: Option<&str>) -> Self
+    {
+        let alignments = headers.iter()
+                                .map(|header| Item::alignment(header))
+                                .collect::<Vec<Alignment>>();
+
+        let mut rows = Vec::with_capacity(items.len() + 2);
+        rows.push(row(&alignments,
+                      headers.iter().map(
+                          |header| escape(&formatters.header(header)))));
+        for item in items
+        {
+            rows.push(row(&alignments,
+                          headers.iter().map(
+                              |header| escape(&item.formatted(header,
+                                                              &formatters,
+                                                              shares)
+                                                   .unwrap_or_default()))));
+        }
+
+        /* Render the total as the last row if its column is visible, or as a
+           separate paragraph otherwise */
+        let mut footer = None;
+        if let Some(sum) = sum
+        {
+            let total = Totals::new(items).formatted(sum, &formatters);
+            match headers.iter().position(|&header| header == sum)
+            {
+                Some(index) =>
+                    rows.push(row(&alignments,
+                                  (0..headers.len()).map(
+                                      |i|
+                                          if i == index { format!("**{}**", escape(&total)) }
+                                          else if i == 0 { "**Total**".into() }
+                                          else { String::new() }))),
+                None => footer = Some(format!("**Total {}:** {}",
+                                              sum,
+                                              escape(&total))),
+            }
+        }
+
+        Self
+        {
+            alignments,
+            rows,
+            footer,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn column_widths(&self) -> Vec<usize>
+    {
+        (0..self.alignments.len()).map(
+            |i| self.rows.iter()
+                         .map(|row| row[i].width())
+                         .max()
+                         .unwrap_or(0)
+                         .max(Self::MIN_WIDTH))
+            .collect()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn delimiter(alignment: Alignment,
+                 width: usize) -> String
+    {
+        let mut delimiter = String::with_capacity(width);
+        for i in 0..width
+        {
+            let is_colon =
+                match alignment
+                {
+                    Alignment::Left => i == 0,
+                    Alignment::Centre => i == 0 || i == width - 1,
+                    Alignment::Right => i == width - 1,
+                };
+            delimiter.push(if is_colon { ':' } else { '-' });
+        }
+
+        delimiter
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn row(alignments: &[Alignment],
+       cells: impl Iterator<Item = String>) -> Vec<Column>
+{
+    cells.zip(alignments)
+         .map(|(cell, &alignment)| Column::from_string(alignment, cell))
+         .collect()
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn escape(text: &str) -> String
+{
+    text.replace('|', "\\|")
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Markdown
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        let column_widths = self.column_widths();
+
+        for (i, row) in self.rows.iter().enumerate()
+        {
+            write!(f, "|")?;
+            for (column, &width) in row.iter().zip(&column_widths)
+            {
+                write!(f, " {} |", column.as_fitted(width))?;
+            }
+            writeln!(f)?;
+
+            /* The delimiter row goes right after the header row */
+            if i == 0
+            {
+                write!(f, "|")?;
+                for (&alignment, &width) in self.alignments.iter()
+                                                           .zip(&column_widths)
+                {
+                    write!(f, " {} |", Self::delimiter(alignment, width))?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        if let Some(footer) = self.footer.as_ref()
+        {
+            writeln!(f, "\n{}", footer)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    fs,
+    io::ErrorKind,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::input::Item;
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Default, Deserialize, Serialize)]
+struct Trip
+{
+    #[serde(default)]
+    packed: BTreeSet<String>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Packing state of the trips, stored next to the gear list, so the gear list
+   itself is never modified by packing */
+#[derive(Default, Deserialize, Serialize)]
+pub struct Packing
+{
+    #[serde(default)]
+    trips: BTreeMap<String, Trip>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Packing
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const EXTENSION: &'static str = "pack.toml";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The state of e.g. `gear.toml` is stored in `gear.pack.toml` */
+    pub fn load(gear_file_name: &str) -> crate::Result<Self>
+    {
+        let path = Path::new(gear_file_name).with_extension(Self::EXTENSION);
+        let mut packing =
+            match fs::read_to_string(&path)
+            {
+                Ok(text) => toml::from_str(&text).map_err(
+                    |error| format!("{}: {}", path.display(), error))?,
+                Err(error) if error.kind() == ErrorKind::NotFound =>
+                    Self::default(),
+                Err(error) => return Err(error.into()),
+            };
+        packing.path = path;
+
+        Ok(packing)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn save(&self) -> crate::Result<()>
+    {
+        /* The state only consists of strings, sets and maps */
+        fs::write(&self.path, toml::to_string(self).unwrap())?;
+        Ok(())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn is_packed(&self, trip: &str, id: &str) -> bool
+    {
+        self.trips.get(trip).is_some_and(|trip| trip.packed.contains(id))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn missing<'a>(&self, trip: &str,
+                              items: &[&'a Item]) -> Vec<&'a Item>
+    {
+        items.iter()
+             .copied()
+             .filter(|item| !self.is_packed(trip, item.id()))
+             .collect()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn check(&mut self, trip: &str, id: &str)
+    {
+        self.trips.entry(trip.to_string())
+                  .or_default()
+                  .packed
+                  .insert(id.to_string());
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn uncheck(&mut self, trip: &str, id: &str)
+    {
+        if let Some(state) = self.trips.get_mut(trip)
+        {
+            state.packed.remove(id);
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn reset(&mut self, trip: &str)
+    {
+        self.trips.remove(trip);
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use std::{
+        env,
+        process,
+    };
+
+    use super::*;
+    use crate::input::fixtures;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const ITEMS: &str = "
+[[base]]
+kind = \"Tent\"
+name = \"Duplex\"
+
+[[base]]
+kind = \"Stove\"
+
+[[consumables]]
+kind = \"Gas\"
+";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn missing(packing: &Packing, trip: &str) -> Vec<String>
+    {
+        let gear = fixtures::gear(ITEMS).unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+        packing.missing(trip, &items)
+               .into_iter()
+               .map(|item| item.id().to_string())
+               .collect()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn checked_items_are_packed_for_their_trip_only()
+    {
+        let mut packing = Packing::default();
+        packing.check("weekend", "Duplex");
+        packing.check("weekend", "Gas");
+
+        assert!(packing.is_packed("weekend", "Duplex"));
+        assert!(!packing.is_packed("thru-hike", "Duplex"));
+        assert_eq!(missing(&packing, "weekend"), vec!["Stove"]);
+        assert_eq!(missing(&packing, "thru-hike"), vec!["Duplex", "Stove", "Gas"]);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn unchecked_items_are_missing_again()
+    {
+        let mut packing = Packing::default();
+        packing.check("weekend", "Duplex");
+        packing.uncheck("weekend", "Duplex");
+        packing.uncheck("thru-hike", "Stove");
+
+        assert!(!packing.is_packed("weekend", "Duplex"));
+        assert_eq!(missing(&packing, "weekend"), vec!["Duplex", "Stove", "Gas"]);
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn reset_forgets_only_the_given_trip()
+    {
+        let mut packing = Packing::default();
+        packing.check("weekend", "Duplex");
+        packing.check("thru-hike", "Duplex");
+        packing.reset("weekend");
+
+        assert!(!packing.is_packed("weekend", "Duplex"));
+        assert!(packing.is_packed("thru-hike", "Duplex"));
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn state_is_saved_next_to_the_gear_list()
+    {
+        let gear = env::temp_dir().join(format!("gear-packing-{}.toml", process::id()));
+        let gear = gear.to_str().unwrap();
+
+        let mut packing = Packing::load(gear).unwrap();
+        assert!(!packing.is_packed("weekend", "Duplex"));
+        packing.check("weekend", "Duplex");
+        packing.save().unwrap();
+
+        let loaded = Packing::load(gear);
+        fs::remove_file(Path::new(gear).with_extension(Packing::EXTENSION)).unwrap();
+        assert!(loaded.unwrap().is_packed("weekend", "Duplex"));
+    }
+}
@@ -0,0 +1,20 @@
+use strsim::osa_distance;
+
+
+/*----------------------------------------------------------------------------*/
+pub fn suggestion<'a>(value: &str,
+                      candidates: impl IntoIterator<Item = &'a str>)
+    -> Option<&'a str>
+{
+    /* Allow roughly one typo for every three characters, so short values
+       don't end up matching everything */
+    let threshold = (value.chars().count()/3).max(1);
+    let value = value.to_lowercase();
+
+    candidates.into_iter()
+              .map(|candidate| (osa_distance(&value, &candidate.to_lowercase()),
+                                candidate))
+              .filter(|&(distance, _)| distance <= threshold)
+              .min()
+              .map(|(_, candidate)| candidate)
+}
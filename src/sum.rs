@@ -4,9 +4,12 @@ use std::fmt::{
     Formatter,
 };
 
-use crate::input::{
-    Meta,
-    Item,
+use crate::{
+    input::{
+        Meta,
+        Item,
+    },
+    totals::Totals,
 };
 
 
@@ -22,25 +25,10 @@ impl Sum
                meta: &Meta,
                items: &[&Item]) -> Self
     {
-        let mut formatted = format!("Total {}: ", column);
-        match column
-        {
-            "weight" =>
-            {
-                let unit = meta.weight();
-                let sum = items.iter().map(|item| item.weight()).sum::<i32>();
-                unit.format_to_string(sum, &mut formatted);
-            },
-            "price" =>
-            {
-                let unit = meta.price();
-                let sum = items.iter().map(|item| item.price()).sum::<f32>();
-                unit.format_to_string(sum, &mut formatted);
-            },
-            _ => unreachable!(),
-        };
-
-        Self(formatted)
+        let totals = Totals::new(items);
+        Self(format!("Total {}: {}",
+                     column,
+                     totals.formatted(column, &meta.formatters())))
     }
 }
 
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod table;
+mod style;
+
+pub use table::Table;
+pub use style::Style;
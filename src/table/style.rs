@@ -0,0 +1,173 @@
+use std::iter;
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy)]
+pub struct Rule
+{
+    left: Option<char>,
+    line: char,
+    cross: Option<char>,
+    right: Option<char>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Rule
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const fn new(left: char, line: char, cross: char, right: char) -> Self
+    {
+        Self
+        {
+            left: Some(left),
+            line,
+            cross: Some(cross),
+            right: Some(right),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn render(&self, column_widths: &[usize]) -> String
+    {
+        let mut rule = String::new();
+        rule.extend(self.left);
+        for (i, &column_width) in column_widths.iter().enumerate()
+        {
+            if i > 0
+            {
+                rule.extend(self.cross);
+            }
+            rule.extend(iter::repeat_n(self.line, column_width));
+        }
+        rule.extend(self.right);
+
+        rule
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Clone, Copy)]
+pub struct Style
+{
+    pub top: Option<Rule>,
+    pub header: Option<Rule>,
+    pub separator: Option<Rule>,
+    pub footer: Option<Rule>,
+    pub bottom: Option<Rule>,
+    pub left: Option<char>,
+    pub inner: Option<char>,
+    pub right: Option<char>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Style
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub const NAMES: [&'static str; 5] = ["ascii",
+                                          "unicode",
+                                          "rounded",
+                                          "compact",
+                                          "borderless"];
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const ASCII: Self =
+        Self
+        {
+            top: Some(Rule::new('+', '-', '+', '+')),
+            header: Some(Rule::new('+', '-', '+', '+')),
+            separator: Some(Rule::new('+', '-', '+', '+')),
+            footer: Some(Rule::new('+', '=', '+', '+')),
+            bottom: Some(Rule::new('+', '-', '+', '+')),
+            left: Some('|'),
+            inner: Some('|'),
+            right: Some('|'),
+        };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const UNICODE: Self =
+        Self
+        {
+            top: Some(Rule::new('┌', '─', '┬', '┐')),
+            header: Some(Rule::new('├', '─', '┼', '┤')),
+            separator: Some(Rule::new('├', '─', '┼', '┤')),
+            footer: Some(Rule::new('╞', '═', '╪', '╡')),
+            bottom: Some(Rule::new('└', '─', '┴', '┘')),
+            left: Some('│'),
+            inner: Some('│'),
+            right: Some('│'),
+        };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const ROUNDED: Self =
+        Self
+        {
+            top: Some(Rule::new('╭', '─', '┬', '╮')),
+            bottom: Some(Rule::new('╰', '─', '┴', '╯')),
+            ..Self::UNICODE
+        };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const COMPACT: Self =
+        Self
+        {
+            top: None,
+            header: Some(Rule
+                         {
+                             left: None,
+                             line: '-',
+                             cross: Some('+'),
+                             right: None,
+                         }),
+            separator: None,
+            footer: Some(Rule
+                         {
+                             left: None,
+                             line: '=',
+                             cross: Some('+'),
+                             right: None,
+                         }),
+            bottom: None,
+            left: None,
+            inner: Some('|'),
+            right: None,
+        };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const BORDERLESS: Self =
+        Self
+        {
+            top: None,
+            header: None,
+            separator: None,
+            footer: None,
+            bottom: None,
+            left: None,
+            inner: None,
+            right: None,
+        };
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn by_name(name: &str) -> Self
+    {
+        match name
+        {
+            "ascii" => Self::ASCII,
+            "unicode" => Self::UNICODE,
+            "rounded" => Self::ROUNDED,
+            "compact" => Self::COMPACT,
+            "borderless" => Self::BORDERLESS,
+            _ => unreachable!(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn borders_width(&self, columns: usize) -> usize
+    {
+        self.left.iter().count()
+            + self.inner.iter().count()*columns.saturating_sub(1)
+            + self.right.iter().count()
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+    Write,
+};
+
+use crate::{
+    input::{
+        Item,
+        Formatters,
+        Shares,
+    },
+    column::{
+        Column,
+        Alignment,
+        Overflow,
+    },
+    table::Style,
+    colors::Colors,
+    totals::Totals,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Table<'a>
+{
+    columns: Vec<Column>,
+    headers: Vec<&'a str>,
+    max_width: Option<usize>,
+    overflow: Overflow,
+    style: Style,
+    has_footer: bool,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Table<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const PADDING: usize = 2;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const MIN_WIDTH: usize = 4;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<'f>(headers: Vec<&'a str>,
+                   items: &[&'f Item],
+                   formatters: Formatters<'f>,
+                   shares: &Shares,
+                   max_width: Option<usize>,
+                   overflow: Overflow,
+                   style: Style,
+                   colors: Option<&Colors<'_>>) -> Self
+    {
+        let mut columns = Vec::new();
+
+        /* Add headers as columns */
+        columns.extend(headers.iter().map(
+            |header| Column::from_string(Alignment::Centre,
+                                         formatters.header(header))
+                         .emphasised(colors.map(Colors::header))));
+
+        /* Add items as columns */
+        for item in items
+        {
+            let emphasis = colors.and_then(|colors| colors.item(item));
+            columns.extend(item.columns(&headers, &formatters, shares)
+                               .map(|column| column.emphasised(emphasis)));
+        }
+
+        /* Add the totals of the columns which have one as the footer */
+        let has_footer =
+            headers.iter().any(|header| Totals::COLUMNS.contains(header));
+        if has_footer
+        {
+            /* The label goes to the first column without a total */
+            let totals = Totals::new(items);
+            let label = headers.iter().position(
+                |header| !Totals::COLUMNS.contains(header));
+            columns.extend(headers.iter().enumerate().map(
+                |(i, header)|
+                {
+                    let content =
+                        if Totals::COLUMNS.contains(header)
+                        {
+                            Some(totals.formatted(header, &formatters))
+                        }
+                        else if label == Some(i)
+                        {
+                            Some("Total".to_string())
+                        }
+                        else
+                        {
+                            None
+                        };
+
+                    Column::new(Item::alignment(header), content.into())
+                        .emphasised(colors.map(Colors::totals))
+                }));
+        }
+
+        Self
+        {
+            columns,
+            headers,
+            max_width,
+            overflow,
+            style,
+            has_footer,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn max_widths_of(&self, width: fn(&Column) -> usize) -> Vec<usize>
+    {
+        let columns_count_in_row = self.headers.len();
+        (0..columns_count_in_row).map(
+            |i| self.columns.iter()
+                            .skip(i)
+                            .step_by(columns_count_in_row)
+                            .map(|column| width(column) + Self::PADDING)
+                            .max()
+                            .unwrap_or(0))
+            .collect()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn column_widths(&self) -> Vec<usize>
+    {
+        let columns_count_in_row = self.headers.len();
+        let mut column_widths = self.max_widths_of(Column::width);
+
+        let width = column_widths.iter().sum::<usize>()
+                    + self.style.borders_width(columns_count_in_row);
+        let mut excess =
+            match self.max_width
+            {
+                Some(max_width) if width > max_width => width - max_width,
+                _ => return column_widths,
+            };
+
+        /* Shrink the least important columns first, at first without breaking
+           up words, then down to the minimum width, but never below that even
+           if the table still does not fit */
+        let mut indices = (0..columns_count_in_row).collect::<Vec<usize>>();
+        indices.sort_by_key(|&i| Item::priority(self.headers[i]));
+        let min_widths = [self.max_widths_of(Column::word_width),
+                          vec![Self::MIN_WIDTH + Self::PADDING; columns_count_in_row]];
+        for min_widths in min_widths.iter()
+        {
+            for &i in indices.iter()
+            {
+                let min_width = min_widths[i].max(Self::MIN_WIDTH + Self::PADDING);
+                let shrinkable = column_widths[i].saturating_sub(min_width);
+                let shrink = shrinkable.min(excess);
+                column_widths[i] -= shrink;
+                excess -= shrink;
+
+                if excess == 0
+                {
+                    return column_widths;
+                }
+            }
+        }
+
+        column_widths
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn rows(&self) -> impl Iterator<Item = &'_ [Column]> + '_
+    {
+        let columns_count_in_row = self.headers.len();
+        (0..self.columns.len())
+            .step_by(columns_count_in_row)
+            .map(move |i| &self.columns[i..i + columns_count_in_row])
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Display for Table<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        let style = &self.style;
+        let column_widths = self.column_widths();
+
+        if let Some(top) = style.top.as_ref()
+        {
+            writeln!(f, "{}", top.render(&column_widths))?;
+        }
+
+        let rows_count = self.columns.len()/self.headers.len();
+        for (i, row) in self.rows().enumerate()
+        {
+            let rule =
+                if i == 1 { style.header }
+                else if self.has_footer && i == rows_count - 1 { style.footer }
+                else { style.separator };
+            if let Some(rule) = rule.filter(|_| i > 0)
+            {
+                writeln!(f, "{}", rule.render(&column_widths))?;
+            }
+
+            /* Cells not fitting into their columns may span multiple lines */
+            let lines =
+                row.iter()
+                   .zip(&column_widths)
+                   .map(|(column, &available_width)|
+                       column.lines(available_width - Self::PADDING,
+                                    self.overflow))
+                   .collect::<Vec<Vec<Column>>>();
+            let height = lines.iter().map(Vec::len).max().unwrap_or(0);
+
+            for line in 0..height
+            {
+                let mut output = String::new();
+                output.extend(style.left);
+                for (j, (column, &available_width)) in lines.iter()
+                                                            .zip(&column_widths)
+                                                            .enumerate()
+                {
+                    if j > 0
+                    {
+                        output.extend(style.inner);
+                    }
+
+                    let width = available_width - Self::PADDING;
+                    match column.get(line)
+                    {
+                        Some(column) =>
+                            write!(output, " {} ", column.as_fitted(width))?,
+                        None => write!(output, " {:1$} ", "", width)?,
+                    }
+                }
+                output.extend(style.right);
+
+                writeln!(f, "{}", output.trim_end())?;
+            }
+        }
+
+        if let Some(bottom) = style.bottom.as_ref()
+        {
+            writeln!(f, "{}", bottom.render(&column_widths))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+mod node;
+mod scope;
+#[allow(clippy::module_inception)]
+mod template;
+
+pub use template::Template;
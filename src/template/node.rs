@@ -0,0 +1,202 @@
+/*----------------------------------------------------------------------------*/
+pub enum Node
+{
+    Text(String),
+    Variable(String),
+    Section
+    {
+        name: String,
+        is_inverted: bool,
+        children: Vec<Node>,
+    },
+}
+
+
+/*----------------------------------------------------------------------------*/
+enum Token<'t>
+{
+    Text(&'t str),
+    Variable(&'t str),
+    Open(&'t str, bool),
+    Close(&'t str),
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'t> Token<'t>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn is_section(&self) -> bool
+    {
+        matches!(self, Self::Open(..) | Self::Close(_))
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Node
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    const OPEN: &'static str = "{{";
+    const CLOSE: &'static str = "}}";
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn parse(template: &str) -> Result<Vec<Self>, String>
+    {
+        let mut tokens = tokenise(template)?;
+        strip_standalone(&mut tokens);
+
+        /* Each open section collects its children until it is closed */
+        let mut stack = vec![(String::new(), false, Vec::new())];
+        for token in tokens
+        {
+            match token
+            {
+                Token::Text(text) =>
+                    stack.last_mut().unwrap().2.push(Self::Text(text.to_string())),
+                Token::Variable(name) =>
+                    stack.last_mut().unwrap().2.push(Self::Variable(name.to_string())),
+                Token::Open(name, is_inverted) =>
+                    stack.push((name.to_string(), is_inverted, Vec::new())),
+                Token::Close(name) =>
+                {
+                    if stack.len() == 1
+                    {
+                        return Err(format!("Section `{}` is closed, but it was \
+                                            never opened", name));
+                    }
+
+                    let (open, is_inverted, children) = stack.pop().unwrap();
+                    if open != name
+                    {
+                        return Err(format!("Section `{}` is closed, but the \
+                                            open section is `{}`", name, open));
+                    }
+
+                    stack.last_mut().unwrap().2.push(
+                        Self::Section
+                        {
+                            name: open,
+                            is_inverted,
+                            children,
+                        });
+                },
+            }
+        }
+
+        match stack.len()
+        {
+            1 => Ok(stack.pop().unwrap().2),
+            _ => Err(format!("Section `{}` is never closed",
+                             stack.last().unwrap().0)),
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn tokenise(template: &str) -> Result<Vec<Token<'_>>, String>
+{
+    let mut tokens = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find(Node::OPEN)
+    {
+        if start > 0
+        {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        rest = &rest[start + Node::OPEN.len()..];
+        let end = rest.find(Node::CLOSE).ok_or_else(
+            || format!("Tag `{}{}` is never closed",
+                       Node::OPEN,
+                       rest.lines().next().unwrap_or_default()))?;
+        let tag = rest[..end].trim();
+        rest = &rest[end + Node::CLOSE.len()..];
+
+        let token =
+            match tag.chars().next()
+            {
+                Some('#') => Token::Open(tag[1..].trim(), false),
+                Some('^') => Token::Open(tag[1..].trim(), true),
+                Some('/') => Token::Close(tag[1..].trim()),
+                Some('!') => continue,
+                _ => Token::Variable(tag),
+            };
+        tokens.push(token);
+    }
+
+    if !rest.is_empty()
+    {
+        tokens.push(Token::Text(rest));
+    }
+
+    Ok(tokens)
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Section tags standing alone on their lines are removed together with their
+   lines, so they do not leave empty lines behind in the output */
+fn strip_standalone(tokens: &mut [Token<'_>])
+{
+    /* Whether the line before or after the i-th token is blank */
+    let is_blank_before = |i: usize|
+        match i.checked_sub(1).map(|j| &tokens[j])
+        {
+            None => true,
+            Some(Token::Text(text)) =>
+            {
+                let line_start = text.rfind('\n').map_or(0, |j| j + 1);
+                text[line_start..].trim().is_empty()
+                    && (line_start > 0 || i == 1)
+            },
+            Some(_) => false,
+        };
+    let is_blank_after = |i: usize|
+        match tokens.get(i + 1)
+        {
+            None => true,
+            Some(Token::Text(text)) =>
+            {
+                let line_end = text.find('\n').map_or(text.len(), |j| j + 1);
+                text[..line_end].trim().is_empty()
+                    && (text.contains('\n') || i + 2 == tokens.len())
+            },
+            Some(_) => false,
+        };
+
+    let is_standalone =
+        (0..tokens.len()).map(|i| tokens[i].is_section()
+                                  && is_blank_before(i)
+                                  && is_blank_after(i))
+                         .collect::<Vec<bool>>();
+
+    /* A text between two standalone tags loses both its first and last line */
+    for i in 0..tokens.len()
+    {
+        if let Token::Text(text) = tokens[i]
+        {
+            let start =
+                if i > 0 && is_standalone[i - 1]
+                {
+                    text.find('\n').map_or(text.len(), |j| j + 1)
+                }
+                else
+                {
+                    0
+                };
+            let end =
+                if is_standalone.get(i + 1).copied().unwrap_or_default()
+                {
+                    text.rfind('\n').map_or(0, |j| j + 1)
+                }
+                else
+                {
+                    text.len()
+                };
+
+            tokens[i] = Token::Text(if start < end { &text[start..end] } else { "" });
+        }
+    }
+}
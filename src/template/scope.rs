@@ -0,0 +1,125 @@
+use crate::{
+    input::{
+        Item,
+        Formatters,
+        Shares,
+    },
+    totals::Totals,
+    groups::grouped,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub enum Lookup<'a>
+{
+    Text(String),
+    List(Vec<Scope<'a>>),
+    Missing,
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Names are looked up in the innermost scope first, then in the enclosing
+   ones */
+pub enum Scope<'a>
+{
+    Root(&'a [&'a Item]),
+    Group(Option<&'a str>, Vec<&'a Item>),
+    Item(&'a Item),
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> Scope<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn lookup(&self, name: &str,
+                         formatters: &Formatters<'_>,
+                         shares: &Shares) -> Option<Lookup<'a>>
+    {
+        use Scope::*;
+        match self
+        {
+            Root(items) => Self::lookup_in_items(name, items, formatters),
+            Group(group, items) =>
+                match name
+                {
+                    "group" => Some(Lookup::Text(group.unwrap_or_default()
+                                                      .to_string())),
+                    _ => Self::lookup_in_items(name, items, formatters),
+                },
+            Item(item) => Self::lookup_in_item(name, item, formatters, shares),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn lookup_in_items(name: &str,
+                       items: &[&'a Item],
+                       formatters: &Formatters<'_>) -> Option<Lookup<'a>>
+    {
+        let lookup =
+            match name
+            {
+                "items" =>
+                    Lookup::List(items.iter().copied().map(Scope::Item).collect()),
+                "groups" =>
+                    Lookup::List(grouped(items).into_iter()
+                                               .map(|(group, items)|
+                                                    Scope::Group(group, items))
+                                               .collect()),
+                "count" => Lookup::Text(items.len().to_string()),
+                _ =>
+                {
+                    let attribute = name.strip_prefix("total.")?;
+                    let (attribute, is_raw) =
+                        match attribute.strip_suffix(".value")
+                        {
+                            Some(attribute) => (attribute, true),
+                            None => (attribute, false),
+                        };
+                    if !Item::NUMERIC_FIELDS.contains(&attribute)
+                    {
+                        return None;
+                    }
+
+                    let totals = Totals::new(items);
+                    Lookup::Text(
+                        if is_raw { totals.number(attribute).to_string() }
+                        else { totals.formatted(attribute, formatters) })
+                },
+            };
+
+        Some(lookup)
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn lookup_in_item(name: &str,
+                      item: &Item,
+                      formatters: &Formatters<'_>,
+                      shares: &Shares) -> Option<Lookup<'a>>
+    {
+        let (attribute, is_raw) =
+            match name.strip_suffix(".value")
+            {
+                Some(attribute) => (attribute, true),
+                None => (name, false),
+            };
+
+        let text =
+            match attribute
+            {
+                "section" => Some(item.section().to_string()),
+                "quantity" => Some(item.quantity().to_string()),
+                "url" => item.url().map(String::from),
+                "worn" => Some(item.is_worn()).filter(|&is_worn| is_worn)
+                                              .map(|_| "worn".to_string()),
+                _ if Item::COLUMNS.contains(&attribute) =>
+                    if is_raw { item.value(attribute, shares)
+                                    .map(|value| value.to_string()) }
+                    else { item.formatted(attribute, formatters, shares) },
+                _ => return None,
+            };
+
+        Some(text.map_or(Lookup::Missing, Lookup::Text))
+    }
+}
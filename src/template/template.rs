@@ -0,0 +1,128 @@
+use std::{
+    fs,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+use crate::{
+    input::{
+        Item,
+        Formatters,
+        Shares,
+    },
+    template::{
+        node::Node,
+        scope::{
+            Scope,
+            Lookup,
+        },
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Template(String);
+
+
+/*----------------------------------------------------------------------------*/
+impl Template
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn from_file(file_name: &str,
+                     items: &[&Item],
+                     formatters: Formatters<'_>,
+                     shares: &Shares) -> crate::Result<Self>
+    {
+        let template = fs::read_to_string(file_name).map_err(
+            |error| format!("{}: {}", file_name, error))?;
+        Self::new(&template, items, formatters, shares).map_err(
+            |error| format!("{}: {}", file_name, error).into())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(template: &str,
+               items: &[&Item],
+               formatters: Formatters<'_>,
+               shares: &Shares) -> crate::Result<Self>
+    {
+        let nodes = Node::parse(template).map_err(
+            |error| format!("Invalid template: {}", error))?;
+
+        let mut output = String::new();
+        let mut scopes = vec![Scope::Root(items)];
+        render(&nodes, &mut scopes, &formatters, shares, &mut output);
+
+        Ok(Self(output))
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+fn lookup<'a>(name: &str,
+              scopes: &[Scope<'a>],
+              formatters: &Formatters<'_>,
+              shares: &Shares) -> Lookup<'a>
+{
+    scopes.iter()
+          .rev()
+          .find_map(|scope| scope.lookup(name, formatters, shares))
+          .unwrap_or(Lookup::Missing)
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Unknown names and missing values are rendered as empty strings and their
+   sections are skipped, while inverted sections are rendered only for them */
+fn render<'a>(nodes: &[Node],
+              scopes: &mut Vec<Scope<'a>>,
+              formatters: &Formatters<'_>,
+              shares: &Shares,
+              output: &mut String)
+{
+    for node in nodes
+    {
+        match node
+        {
+            Node::Text(text) => output.push_str(text),
+            Node::Variable(name) =>
+                if let Lookup::Text(text) = lookup(name, scopes, formatters, shares)
+                {
+                    output.push_str(&text);
+                },
+            Node::Section { name, is_inverted, children } =>
+                match (lookup(name, scopes, formatters, shares), is_inverted)
+                {
+                    (Lookup::List(list), false) =>
+                        for scope in list
+                        {
+                            scopes.push(scope);
+                            render(children, scopes, formatters, shares, output);
+                            scopes.pop();
+                        },
+                    (Lookup::Text(text), false) if !text.is_empty() =>
+                        render(children, scopes, formatters, shares, output),
+                    (Lookup::List(list), true) if list.is_empty() =>
+                        render(children, scopes, formatters, shares, output),
+                    (Lookup::Text(text), true) if text.is_empty() =>
+                        render(children, scopes, formatters, shares, output),
+                    (Lookup::Missing, true) =>
+                        render(children, scopes, formatters, shares, output),
+                    _ => (),
+                },
+        }
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Display for Template
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
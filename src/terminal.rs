@@ -0,0 +1,29 @@
+use std::{
+    env,
+    io::{
+        self,
+        IsTerminal,
+    },
+};
+
+use terminal_size::{
+    terminal_size,
+    Width,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub fn width() -> Option<usize>
+{
+    terminal_size().map(|(Width(width), _)| width as usize)
+}
+
+
+/*----------------------------------------------------------------------------*/
+/* Colors are only used when writing to a terminal and the user did not opt
+   out by setting `NO_COLOR` (see https://no-color.org) */
+pub fn is_colored() -> bool
+{
+    io::stdout().is_terminal()
+        && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
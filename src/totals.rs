@@ -0,0 +1,131 @@
+use crate::input::{
+    Item,
+    Number,
+    Formatters,
+};
+
+
+/*----------------------------------------------------------------------------*/
+pub struct Totals
+{
+    weight: i32,
+    price: f32,
+    quantity: u32,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Totals
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    /* The columns which have totals, the group weight shares are left out, as
+       their sum is only meaningful within a single group */
+    pub const COLUMNS: [&'static str; 5] = ["weight",
+                                            "price",
+                                            "quantity",
+                                            "weight%",
+                                            "price%"];
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn new(items: &[&Item]) -> Self
+    {
+        /* Weight and price are given per piece, and summing up floats starts
+           from negative zero, which would be printed as such for no items */
+        Self
+        {
+            weight: items.iter()
+                         .map(|item| item.weight()*item.quantity() as i32)
+                         .sum(),
+            price: items.iter()
+                        .map(|item| item.price()*item.quantity() as f32)
+                        .fold(0.0, |total, price| total + price),
+            quantity: items.iter().map(|item| item.quantity()).sum(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn number(&self, attribute: &str) -> Number
+    {
+        match attribute
+        {
+            "weight" => Number::Integer(self.weight),
+            "price" => Number::Float(self.price),
+            "quantity" => Number::Integer(self.quantity as i32),
+            _ => unreachable!(),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn formatted(&self, attribute: &str,
+                            formatters: &Formatters<'_>) -> String
+    {
+        let unit =
+            match attribute
+            {
+                "weight" => formatters.weight,
+                "price" => formatters.price,
+                "quantity" => return self.quantity.to_string(),
+                /* The shares are relative to the same items */
+                "weight%" | "price%" =>
+                {
+                    let total = self.number(&attribute[..attribute.len() - 1]);
+                    let share = if total.as_f64() == 0.0 { 0.0 } else { 100.0 };
+                    return format!("{:.1}%", share);
+                },
+                _ => unreachable!(),
+            };
+
+        let mut formatted = String::new();
+        unit.format_to_string(self.number(attribute), &mut formatted);
+        formatted
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::input::fixtures;
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn totals_count_each_piece_of_the_items()
+    {
+        let gear = fixtures::gear("
+[[base]]
+kind = \"Tent\"
+weight = 800
+price = 300.0
+
+[[consumables]]
+kind = \"Gas\"
+weight = 230
+price = 6.5
+quantity = 3
+").unwrap();
+        let items = gear.items().collect::<Vec<&Item>>();
+        let totals = Totals::new(&items);
+        let formatters = gear.meta().formatters();
+
+        assert_eq!(totals.formatted("weight", &formatters), "1490g");
+        assert_eq!(totals.formatted("price", &formatters), "$319.5");
+        assert_eq!(totals.formatted("quantity", &formatters), "4");
+        assert_eq!(totals.formatted("weight%", &formatters), "100.0%");
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    #[test]
+    fn totals_of_no_items_are_zero()
+    {
+        let gear = fixtures::gear("").unwrap();
+        let totals = Totals::new(&[]);
+        let formatters = gear.meta().formatters();
+
+        assert_eq!(totals.formatted("weight", &formatters), "0g");
+        assert_eq!(totals.formatted("price", &formatters), "$0");
+        assert_eq!(totals.formatted("quantity", &formatters), "0");
+        assert_eq!(totals.formatted("price%", &formatters), "0.0%");
+    }
+}
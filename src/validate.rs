@@ -13,6 +13,12 @@ pub trait Validate
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn expected(&self) -> String;
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn suggestion(&self, _invalid: &str) -> Option<&str>
+    {
+        None
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn missing_message(&self, owner: &str) -> String
     {
@@ -26,10 +32,17 @@ pub trait Validate
     fn invalid_message(&self, owner: &str,
                               invalid: &str) -> String
     {
-        format!("Expected {} for `{}` on `{}`, but found: `{}`",
-                self.expected(),
-                self.attribute(),
-                owner,
-                invalid)
+        let mut message = format!("Expected {} for `{}` on `{}`, but found: `{}`",
+                                  self.expected(),
+                                  self.attribute(),
+                                  owner,
+                                  invalid);
+
+        if let Some(suggestion) = self.suggestion(invalid)
+        {
+            message.push_str(&format!(", did you mean `{}`?", suggestion));
+        }
+
+        message
     }
 }
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use serde::{
+    de,
+    Deserialize,
+    Deserializer,
+};
+
+use crate::{
+    input::Item,
+    suggest::suggestion,
+};
+
+
+/*----------------------------------------------------------------------------*/
+fn check<E>(attribute: &str,
+            expected: &[&str]) -> Result<(), E>
+    where E: de::Error
+{
+    if expected.contains(&attribute)
+    {
+        return Ok(());
+    }
+
+    let mut message = format!("unknown attribute `{}`, expected `{}`",
+                              attribute,
+                              expected.join("` or `"));
+    if let Some(suggestion) = suggestion(attribute, expected.iter().copied())
+    {
+        message.push_str(&format!(", did you mean `{}`?", suggestion));
+    }
+
+    Err(E::custom(message))
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub fn deserialize_attributes<'de, D>(deserializer: D)
+    -> Result<Vec<String>, D::Error>
+    where D: Deserializer<'de>
+{
+    let attributes = Vec::<String>::deserialize(deserializer)?;
+    for attribute in attributes.iter()
+    {
+        check(attribute, &Item::FIELDS)?;
+    }
+
+    Ok(attributes)
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub fn deserialize_numeric_attribute<'de, D>(deserializer: D)
+    -> Result<String, D::Error>
+    where D: Deserializer<'de>
+{
+    let attribute = String::deserialize(deserializer)?;
+    check(&attribute, &Item::NUMERIC_FIELDS)?;
+
+    Ok(attribute)
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub fn deserialize_labels<'de, D>(deserializer: D)
+    -> Result<HashMap<String, String>, D::Error>
+    where D: Deserializer<'de>
+{
+    let labels = HashMap::<String, String>::deserialize(deserializer)?;
+    for column in labels.keys()
+    {
+        check(column, &Item::COLUMNS)?;
+    }
+
+    Ok(labels)
+}
+
+
+/*----------------------------------------------------------------------------*/
+pub fn deserialize_presets<'de, D>(deserializer: D)
+    -> Result<HashMap<String, Vec<String>>, D::Error>
+    where D: Deserializer<'de>
+{
+    let presets = HashMap::<String, Vec<String>>::deserialize(deserializer)?;
+    for (name, columns) in presets.iter()
+    {
+        if columns.is_empty()
+        {
+            return Err(de::Error::custom(
+                format!("column preset `{}` is empty", name)));
+        }
+
+        for column in columns
+        {
+            check(column, &Item::COLUMNS)?;
+        }
+    }
+
+    Ok(presets)
+}
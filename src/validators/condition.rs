@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+use crate::input::{
+    Item,
+    Section,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Default, Deserialize)]
+pub struct Condition
+{
+    section: Option<Section>,
+    group: Option<String>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Condition
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn applies(&self, item: &Item) -> bool
+    {
+        self.section.is_none_or(|section| section == item.section())
+            && self.group.as_ref().is_none_or(
+                |group| item.group() == Some(group.as_str()))
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn describe(&self) -> String
+    {
+        match (self.section, self.group.as_ref())
+        {
+            (None, None) => String::new(),
+            (Some(section), None) => format!(" (for `{}` items)", section),
+            (None, Some(group)) => format!(" (for items in group `{}`)", group),
+            (Some(section), Some(group)) =>
+                format!(" (for `{}` items in group `{}`)", section, group),
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     input::Item,
     validate::Validate,
     validators::Validity,
+    suggest::suggestion,
 };
 
 
@@ -25,6 +26,12 @@ impl DistancesValues
     {
         self.values.is_empty()
     }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn values(&self) -> impl Iterator<Item = &str>
+    {
+        self.values.iter().map(String::as_str)
+    }
 }
 
 
@@ -40,13 +47,17 @@ impl Validate for DistancesValues
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn expected(&self) -> String
     {
-        let mut values = self.values.iter()
-                                    .map(String::as_str)
-                                    .collect::<Vec<&str>>();
+        let mut values = self.values().collect::<Vec<&str>>();
         values.sort_unstable();
         format!("`{}`", values.join("` or `"))
     }
 
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn suggestion(&self, invalid: &str) -> Option<&str>
+    {
+        suggestion(invalid, self.values())
+    }
+
     /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
     fn validate(&self, item: &Item) -> crate::Result<()>
     {
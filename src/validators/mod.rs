@@ -3,9 +3,24 @@ mod validator;
 mod group_values;
 mod distances_values;
 mod temperatures_values;
+mod attributes;
+mod condition;
+mod required_attributes;
+mod numeric_bounds;
+mod positive_quantity;
+mod rules;
 
 pub use validity::Validity;
 pub use validator::Validator;
 pub use group_values::GroupValues;
 pub use distances_values::DistancesValues;
 pub use temperatures_values::TemperaturesValues;
+pub use condition::Condition;
+pub use required_attributes::RequiredAttributes;
+pub use numeric_bounds::NumericBounds;
+pub use positive_quantity::PositiveQuantity;
+pub use rules::Rules;
+pub use attributes::{
+    deserialize_labels,
+    deserialize_presets,
+};
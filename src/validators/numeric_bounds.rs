@@ -0,0 +1,88 @@
+use serde::Deserialize;
+
+use crate::{
+    input::Item,
+    validate::Validate,
+    validators::{
+        Condition,
+        attributes::deserialize_numeric_attribute,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Deserialize)]
+pub struct NumericBounds
+{
+    #[serde(deserialize_with = "deserialize_numeric_attribute")]
+    attribute: String,
+    greater_than: Option<f64>,
+    at_least: Option<f64>,
+    less_than: Option<f64>,
+    at_most: Option<f64>,
+    #[serde(default)]
+    when: Condition,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl NumericBounds
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn is_within(&self, value: f64) -> bool
+    {
+        self.greater_than.is_none_or(|bound| value > bound)
+            && self.at_least.is_none_or(|bound| value >= bound)
+            && self.less_than.is_none_or(|bound| value < bound)
+            && self.at_most.is_none_or(|bound| value <= bound)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Validate for NumericBounds
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn attribute(&self) -> String
+    {
+        self.attribute.clone()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn expected(&self) -> String
+    {
+        let bounds = [("greater than", self.greater_than),
+                      ("at least", self.at_least),
+                      ("less than", self.less_than),
+                      ("at most", self.at_most)];
+        let bounds = bounds.iter()
+                           .filter_map(|(relation, bound)|
+                               bound.map(|bound| format!("{} `{}`", relation, bound)))
+                           .collect::<Vec<String>>();
+
+        if bounds.is_empty()
+        {
+            format!("any value{}", self.when.describe())
+        }
+        else
+        {
+            format!("a value {}{}", bounds.join(" and "), self.when.describe())
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn validate(&self, item: &Item) -> crate::Result<()>
+    {
+        if !self.when.applies(item)
+        {
+            return Ok(());
+        }
+
+        match item.number(&self.attribute)
+        {
+            Some(value) if !self.is_within(value.as_f64()) =>
+                Err(self.invalid_message(item.id(), &value.to_string()).into()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    input::Item,
+    validate::Validate,
+};
+
+
+/*----------------------------------------------------------------------------*/
+/* Always in effect, as an item of no pieces could not be listed or packed */
+pub struct PositiveQuantity;
+
+
+/*----------------------------------------------------------------------------*/
+impl Validate for PositiveQuantity
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn attribute(&self) -> String
+    {
+        "quantity".into()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn expected(&self) -> String
+    {
+        "a value at least `1`".into()
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn validate(&self, item: &Item) -> crate::Result<()>
+    {
+        match item.quantity()
+        {
+            0 => Err(self.invalid_message(item.id(), "0").into()),
+            _ => Ok(()),
+        }
+    }
+}
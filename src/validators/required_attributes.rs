@@ -0,0 +1,58 @@
+use serde::Deserialize;
+
+use crate::{
+    input::Item,
+    validate::Validate,
+    validators::{
+        Condition,
+        attributes::deserialize_attributes,
+    },
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Deserialize)]
+pub struct RequiredAttributes
+{
+    #[serde(deserialize_with = "deserialize_attributes")]
+    attributes: Vec<String>,
+    #[serde(default)]
+    when: Condition,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Validate for RequiredAttributes
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn attribute(&self) -> String
+    {
+        self.attributes.join("`, `")
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn expected(&self) -> String
+    {
+        format!("a value{}", self.when.describe())
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn validate(&self, item: &Item) -> crate::Result<()>
+    {
+        if !self.when.applies(item)
+        {
+            return Ok(());
+        }
+
+        match self.attributes.iter().find(|attribute| !item.is_present(attribute))
+        {
+            None => Ok(()),
+            Some(attribute) =>
+                Err(format!("Expected {} for `{}` on `{}`, but the value is \
+                             missing",
+                            self.expected(),
+                            attribute,
+                            item.id()).into()),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+use crate::validators::{
+    RequiredAttributes,
+    NumericBounds,
+};
+
+
+/*----------------------------------------------------------------------------*/
+#[derive(Deserialize)]
+pub struct Rules
+{
+    #[serde(default)]
+    required: Vec<RequiredAttributes>,
+    #[serde(default)]
+    bounds: Vec<NumericBounds>,
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl Rules
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn required(&self) -> &[RequiredAttributes]
+    {
+        &self.required
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn bounds(&self) -> &[NumericBounds]
+    {
+        &self.bounds
+    }
+}
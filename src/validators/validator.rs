@@ -1,10 +1,14 @@
 use crate::{
     input::Item,
+    lints::Check,
     validate::Validate,
     validators::{
         GroupValues,
         DistancesValues,
         TemperaturesValues,
+        RequiredAttributes,
+        NumericBounds,
+        PositiveQuantity,
     },
 };
 
@@ -15,6 +19,9 @@ pub enum Validator<'a>
     GroupValues(&'a GroupValues),
     DistancesValues(&'a DistancesValues),
     TemperaturesValues(&'a TemperaturesValues),
+    RequiredAttributes(&'a RequiredAttributes),
+    NumericBounds(&'a NumericBounds),
+    PositiveQuantity(&'a PositiveQuantity),
 }
 
 
@@ -31,6 +38,40 @@ impl<'a> Validator<'a>
             GroupValues(groups) => groups.validate(item),
             DistancesValues(distances) => distances.validate(item),
             TemperaturesValues(temperatures) => temperatures.validate(item),
+            RequiredAttributes(required) => required.validate(item),
+            NumericBounds(bounds) => bounds.validate(item),
+            PositiveQuantity(quantity) => quantity.validate(item),
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn check(&self) -> Check
+    {
+        use Validator::*;
+
+        match self
+        {
+            GroupValues(_) => Check::GroupValues,
+            DistancesValues(_) => Check::DistancesValues,
+            TemperaturesValues(_) => Check::TemperaturesValues,
+            RequiredAttributes(_) => Check::RequiredAttributes,
+            NumericBounds(_) | PositiveQuantity(_) => Check::NumericBounds,
+        }
+    }
+
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    pub fn attribute(&self) -> String
+    {
+        use Validator::*;
+
+        match self
+        {
+            GroupValues(groups) => groups.attribute(),
+            DistancesValues(distances) => distances.attribute(),
+            TemperaturesValues(temperatures) => temperatures.attribute(),
+            RequiredAttributes(required) => required.attribute(),
+            NumericBounds(bounds) => bounds.attribute(),
+            PositiveQuantity(quantity) => quantity.attribute(),
         }
     }
 }
@@ -67,3 +108,36 @@ impl<'a> From<&'a TemperaturesValues> for Validator<'a>
         Self::TemperaturesValues(temperatures_values)
     }
 }
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> From<&'a RequiredAttributes> for Validator<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn from(required_attributes: &'a RequiredAttributes) -> Self
+    {
+        Self::RequiredAttributes(required_attributes)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> From<&'a NumericBounds> for Validator<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn from(numeric_bounds: &'a NumericBounds) -> Self
+    {
+        Self::NumericBounds(numeric_bounds)
+    }
+}
+
+
+/*----------------------------------------------------------------------------*/
+impl<'a> From<&'a PositiveQuantity> for Validator<'a>
+{
+    /*- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+    fn from(positive_quantity: &'a PositiveQuantity) -> Self
+    {
+        Self::PositiveQuantity(positive_quantity)
+    }
+}